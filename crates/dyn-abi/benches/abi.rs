@@ -1,6 +1,6 @@
 use alloy_dyn_abi::{DynSolType, DynSolValue};
 use alloy_primitives::{hex, U256};
-use alloy_sol_types::{sol, sol_data, SolType, SolValue};
+use alloy_sol_types::{sol, sol_data, SolCall, SolType, SolValue};
 use criterion::{
     criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
 };
@@ -98,6 +98,28 @@ fn sol_types_encode(c: &mut Criterion) {
         b.iter(|| black_box(&input).abi_encode());
     });
 
+    g.bench_function("calls", |b| {
+        let input = encode_calls_input();
+        b.iter(|| {
+            let mut out = Vec::new();
+            let mut offsets = Vec::with_capacity(input.len());
+            for call in black_box(&input) {
+                offsets.push(out.len());
+                out.extend(call.abi_encode());
+            }
+            (out, offsets)
+        });
+    });
+
+    g.bench_function("calls/many", |b| {
+        let input = encode_calls_input();
+        b.iter(|| {
+            let mut out = Vec::new();
+            let offsets = transferCall::abi_encode_many(black_box(&input), &mut out);
+            (out, offsets)
+        });
+    });
+
     g.finish();
 }
 
@@ -114,6 +136,34 @@ fn sol_types_decode(c: &mut Criterion) {
         b.iter(|| sol_data::String::abi_decode(black_box(&input), false).unwrap());
     });
 
+    g.bench_function("calls", |b| {
+        let input = decode_calls_input();
+        b.iter(|| {
+            for data in black_box(&input) {
+                black_box(transferCall::abi_decode(data, false).unwrap());
+            }
+        });
+    });
+
+    g.bench_function("calls/validate", |b| {
+        let input = decode_calls_input();
+        b.iter(|| {
+            for data in black_box(&input) {
+                black_box(transferCall::abi_decode(data, true).unwrap());
+            }
+        });
+    });
+
+    g.bench_function("returns/word", |b| {
+        let input = decode_word_input();
+        b.iter(|| balanceOfCall::abi_decode_returns(black_box(&input), false).unwrap());
+    });
+
+    g.bench_function("returns/word/validate", |b| {
+        let input = decode_word_input();
+        b.iter(|| balanceOfCall::abi_decode_returns(black_box(&input), true).unwrap());
+    });
+
     g.finish();
 }
 
@@ -130,6 +180,9 @@ sol! {
         uint256 amountOutMinimum;
         uint160 sqrtPriceLimitX96;
     }
+
+    function transfer(address to, uint256 amount, bytes data);
+    function balanceOf(address owner) returns (uint256);
 }
 
 fn encode_single_input() -> String {
@@ -149,6 +202,20 @@ fn encode_struct_input() -> Input {
     }
 }
 
+fn encode_calls_input() -> Vec<transferCall> {
+    (0..1000u64)
+        .map(|i| transferCall {
+            to: hex!("299A299A22F8C7397d9DB3702439069d951AeA74").into(),
+            amount: U256::from(i),
+            data: vec![0x42; (i % 64) as usize],
+        })
+        .collect()
+}
+
+fn decode_calls_input() -> Vec<Vec<u8>> {
+    encode_calls_input().iter().map(SolCall::abi_encode).collect()
+}
+
 fn encode_struct_input_tokens() -> [ethabi::Token; 8] {
     let input = encode_struct_input();
     [
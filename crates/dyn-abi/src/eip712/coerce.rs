@@ -5,6 +5,33 @@ use alloc::{
 };
 use alloy_primitives::{Address, Function, I256, U256};
 
+/// Parses a Solidity type and coerces a [`serde_json::Value`] to a
+/// [`DynSolValue`] of that type.
+///
+/// This is a convenience wrapper around [`DynSolType::parse`] and
+/// [`DynSolType::coerce_json`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{coerce, DynSolValue};
+/// use alloy_primitives::U256;
+/// use serde_json::json;
+///
+/// let value = coerce("uint256[]", &json!(["1", "2"]))?;
+/// assert_eq!(
+///     value,
+///     DynSolValue::Array(vec![
+///         DynSolValue::Uint(U256::from(1), 256),
+///         DynSolValue::Uint(U256::from(2), 256),
+///     ])
+/// );
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
+pub fn coerce(ty: &str, value: &serde_json::Value) -> Result<DynSolValue> {
+    DynSolType::parse(ty)?.coerce_json(value)
+}
+
 impl DynSolType {
     /// Coerce a [`serde_json::Value`] to a [`DynSolValue`] via this type.
     pub fn coerce_json(&self, value: &serde_json::Value) -> Result<DynSolValue> {
@@ -178,6 +205,28 @@ mod tests {
     use alloc::{borrow::ToOwned, string::ToString};
     use serde_json::json;
 
+    #[test]
+    fn coerce_type_str() {
+        let value = coerce("uint256[]", &json!(["1", "0x2", 3])).unwrap();
+        assert_eq!(
+            value,
+            DynSolValue::Array(vec![
+                DynSolValue::Uint(U256::from(1), 256),
+                DynSolValue::Uint(U256::from(2), 256),
+                DynSolValue::Uint(U256::from(3), 256),
+            ])
+        );
+
+        let value = coerce("(bool,string)", &json!([true, "hi"])).unwrap();
+        assert_eq!(
+            value,
+            DynSolValue::Tuple(vec![DynSolValue::Bool(true), DynSolValue::String("hi".into())])
+        );
+
+        assert!(coerce("uint8[]", &json!(["256"])).is_err());
+        assert!(coerce("uint256[", &json!([])).is_err());
+    }
+
     #[test]
     fn it_coerces() {
         let j = json!({
@@ -663,6 +663,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hash_struct_dynamic_fields() {
+        let json = json!({
+            "types": {
+                "EIP712Domain": [],
+                "Note": [
+                    { "name": "text", "type": "string" },
+                    { "name": "data", "type": "bytes" },
+                    { "name": "count", "type": "uint256" }
+                ]
+            },
+            "primaryType": "Note",
+            "domain": {},
+            "message": {
+                "text": "Hello, Bob!",
+                "data": "0xdeadbeef",
+                "count": 2
+            }
+        });
+
+        let typed_data: TypedData = serde_json::from_value(json).unwrap();
+
+        // `string` and `bytes` are encoded as the hash of their contents
+        let encoded = typed_data.encode_data().unwrap();
+        assert_eq!(encoded.len(), 3 * 32);
+        assert_eq!(encoded[..32], keccak256("Hello, Bob!"));
+        assert_eq!(encoded[32..64], keccak256(hex::decode("deadbeef").unwrap()));
+        assert_eq!(encoded[64..], B256::with_last_byte(2));
+
+        let type_hash = keccak256("Note(string text,bytes data,uint256 count)");
+        assert_eq!(typed_data.type_hash().unwrap(), type_hash);
+        assert_eq!(
+            typed_data.hash_struct().unwrap(),
+            keccak256([type_hash.as_slice(), &encoded].concat())
+        );
+
+        sol! {
+            struct Note {
+                string text;
+                bytes data;
+                uint256 count;
+            }
+        }
+        let note = Note {
+            text: "Hello, Bob!".into(),
+            data: hex::decode("deadbeef").unwrap(),
+            count: alloy_primitives::U256::from(2),
+        };
+        assert_eq!(typed_data.hash_struct().unwrap(), note.eip712_hash_struct());
+    }
+
     #[test]
     fn from_sol_struct() {
         sol! {
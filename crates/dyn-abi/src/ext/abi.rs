@@ -257,4 +257,40 @@ mod tests {
         assert!(func.abi_decode_output(&bad_response, true).is_err());
         assert!(func.abi_decode_output(&bad_response, false).is_err());
     }
+
+    #[test]
+    fn encode_input_typeck() {
+        use alloy_sol_types::{sol_data, SolType};
+
+        let func = Function::parse("function foo(uint8 a, (string,bool) b)").unwrap();
+        let args = [
+            DynSolValue::Uint(U256::from(1), 8),
+            DynSolValue::Tuple(vec![DynSolValue::String("hi".into()), DynSolValue::Bool(true)]),
+        ];
+        let encoded = func.abi_encode_input(&args).unwrap();
+        let params = <(sol_data::Uint<8>, (sol_data::String, sol_data::Bool))>::abi_encode_params(
+            &(1u8, (String::from("hi"), true)),
+        );
+        assert_eq!(encoded[..4], func.selector()[..]);
+        assert_eq!(encoded[4..], params[..]);
+        assert_eq!(func.abi_encode_input_raw(&args).unwrap(), params);
+        assert_eq!(func.abi_decode_input(&encoded[4..], true).unwrap(), args);
+
+        let wrong = [DynSolValue::Uint(U256::from(1), 8), DynSolValue::String("hi".into())];
+        assert_eq!(
+            func.abi_encode_input(&wrong),
+            Err(CrateError::TypeMismatch {
+                expected: "(string,bool)".into(),
+                actual: "string".into(),
+            })
+        );
+
+        let wrong_size = [DynSolValue::Uint(U256::from(1), 256), args[1].clone()];
+        assert!(matches!(func.abi_encode_input(&wrong_size), Err(CrateError::TypeMismatch { .. })));
+
+        assert_eq!(
+            func.abi_encode_input(&args[..1]),
+            Err(CrateError::EncodeLengthMismatch { expected: 2, actual: 1 })
+        );
+    }
 }
@@ -59,7 +59,9 @@ pub use resolve::{ResolveSolEvent, ResolveSolType};
 #[cfg(feature = "eip712")]
 pub mod eip712;
 #[cfg(feature = "eip712")]
-pub use eip712::{parser as eip712_parser, Eip712Types, PropertyDef, Resolver, TypeDef, TypedData};
+pub use eip712::{
+    coerce::coerce, parser as eip712_parser, Eip712Types, PropertyDef, Resolver, TypeDef, TypedData,
+};
 
 #[doc(no_inline)]
 pub use alloy_sol_types::{
@@ -193,6 +193,20 @@ impl DynSolValue {
     /// The Solidity type. This returns the Solidity type corresponding to this
     /// value, if it is known. A type will not be known if the value contains
     /// an empty sequence, e.g. `T[0]`.
+    ///
+    /// The element type of arrays is inferred from their first element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    ///
+    /// let value = DynSolValue::Array(vec![DynSolValue::Bool(true)]);
+    /// assert_eq!(value.as_type(), Some(DynSolType::Array(Box::new(DynSolType::Bool))));
+    ///
+    /// // the element type of an empty array cannot be inferred
+    /// assert_eq!(DynSolValue::Array(vec![]).as_type(), None);
+    /// ```
     pub fn as_type(&self) -> Option<DynSolType> {
         let ty = match self {
             Self::Address(_) => DynSolType::Address,
@@ -785,3 +799,38 @@ impl DynSolValue {
         self.as_fixed_seq().map(Self::encode_seq)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn as_type() {
+        assert_eq!(DynSolValue::Address(Address::ZERO).as_type(), Some(DynSolType::Address));
+        assert_eq!(DynSolValue::Uint(U256::ZERO, 8).as_type(), Some(DynSolType::Uint(8)));
+        assert_eq!(DynSolValue::Int(I256::ZERO, 32).as_type(), Some(DynSolType::Int(32)));
+        assert_eq!(
+            DynSolValue::FixedBytes(Word::ZERO, 4).as_type(),
+            Some(DynSolType::FixedBytes(4))
+        );
+        assert_eq!(DynSolValue::String(String::new()).as_type(), Some(DynSolType::String));
+
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Bool(true),
+            DynSolValue::Array(vec![DynSolValue::Bytes(vec![1]), DynSolValue::Bytes(vec![])]),
+            DynSolValue::FixedArray(vec![DynSolValue::Uint(U256::ZERO, 256)]),
+        ]);
+        let ty = DynSolType::parse("(bool,bytes[],uint256[1])").unwrap();
+        assert_eq!(value.as_type(), Some(ty));
+
+        assert_eq!(DynSolValue::Array(vec![]).as_type(), None);
+        assert_eq!(DynSolValue::FixedArray(vec![]).as_type(), None);
+        let nested_empty = DynSolValue::Tuple(vec![
+            DynSolValue::Bool(true),
+            DynSolValue::Array(vec![DynSolValue::Array(vec![])]),
+        ]);
+        assert_eq!(nested_empty.as_type(), None);
+        assert_eq!(DynSolValue::Tuple(vec![]).as_type(), Some(DynSolType::Tuple(vec![])));
+    }
+}
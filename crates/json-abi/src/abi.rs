@@ -109,6 +109,72 @@ impl JsonAbi {
         serde_json::from_str(json)
     }
 
+    /// Extracts a single contract's ABI from the output of `solc --combined-json abi`.
+    ///
+    /// Contracts are keyed by `<file>:<name>` in the `contracts` object. The
+    /// `contract_name` may either be the full `<file>:<name>` key, or just the
+    /// contract name, in which case the first matching contract is returned.
+    ///
+    /// Both the stringified ABI emitted by older `solc` versions and the nested
+    /// JSON array emitted by newer ones are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::JsonAbi;
+    /// let json = r#"{
+    ///     "contracts": {
+    ///         "Counter.sol:Counter": {
+    ///             "abi": [{
+    ///                 "type": "function",
+    ///                 "name": "count",
+    ///                 "inputs": [],
+    ///                 "outputs": [{ "name": "", "type": "uint256" }],
+    ///                 "stateMutability": "view"
+    ///             }]
+    ///         }
+    ///     },
+    ///     "version": "0.8.23"
+    /// }"#;
+    /// let abi = JsonAbi::from_combined_json(json, "Counter")?;
+    /// assert_eq!(abi.function("count").unwrap()[0].outputs[0].ty, "uint256");
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn from_combined_json(json: &str, contract_name: &str) -> Result<Self, serde_json::Error> {
+        use serde::de::Error as _;
+        use serde_json::Value;
+
+        #[derive(Deserialize)]
+        struct CombinedJson {
+            contracts: BTreeMap<String, Value>,
+        }
+
+        let CombinedJson { mut contracts } = serde_json::from_str(json)?;
+        let key = if contracts.contains_key(contract_name) {
+            contract_name.into()
+        } else {
+            contracts
+                .keys()
+                .find(|key| key.rsplit_once(':').map(|(_, name)| name) == Some(contract_name))
+                .cloned()
+                .ok_or_else(|| {
+                    serde_json::Error::custom(format!(
+                        "contract `{contract_name}` not found in combined JSON"
+                    ))
+                })?
+        };
+        let Some(Value::Object(mut contract)) = contracts.remove(&key) else {
+            return Err(serde_json::Error::custom(format!("invalid contract object for `{key}`")));
+        };
+        match contract.remove("abi") {
+            Some(Value::String(abi)) => serde_json::from_str(&abi),
+            // params borrow their strings when deserializing, which an owned `Value` can't lend
+            Some(abi) => serde_json::from_str(&serde_json::to_string(&abi)?),
+            None => Err(serde_json::Error::missing_field("abi")),
+        }
+    }
+
     /// Loads contract from a JSON [Reader](std::io::Read).
     ///
     /// This is a convenience wrapper around [`serde_json::from_str`].
@@ -47,6 +47,9 @@ pub use internal_type::InternalType;
 
 mod to_sol;
 
+mod validate;
+pub use validate::AbiError;
+
 pub(crate) mod utils;
 
 /// A JSON ABI function's state mutability.
@@ -0,0 +1,148 @@
+use crate::{InternalType, JsonAbi, Param};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+use parser::{is_valid_identifier, TypeSpecifier};
+
+/// An invalid entry found when [validating](JsonAbi::validate) a [`JsonAbi`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbiError {
+    /// The item in which the error was found, e.g. `function transfer`.
+    pub item: String,
+    /// The error message.
+    pub message: String,
+}
+
+impl fmt::Display for AbiError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.item, self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AbiError {}
+
+impl JsonAbi {
+    /// Validates all the items in this ABI.
+    ///
+    /// This checks that:
+    /// - all parameter types are valid Solidity types, such as `uint256` or `tuple[]`, with valid
+    ///   sizes. Since `solc` emits the names of contracts, enums and structs in library ABIs, these
+    ///   are also accepted if they match the parameter's internal type;
+    /// - `tuple` parameters, and only these, have components;
+    /// - functions, events, and errors have non-empty, valid identifiers as names;
+    /// - events have at most 3 indexed parameters, or 4 if they are anonymous.
+    ///
+    /// Deserialization only checks the syntax of type strings, so this is
+    /// useful to reject malformed ABIs from untrusted sources before they are
+    /// used.
+    ///
+    /// Returns all the errors found, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let abi = JsonAbi::parse(["function foo(uint7 a)"])?;
+    /// let errors = abi.validate().unwrap_err();
+    /// assert_eq!(
+    ///     errors[0].to_string(),
+    ///     "function foo: invalid type `uint7`: invalid size for type: uint7"
+    /// );
+    /// # Ok::<(), alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<AbiError>> {
+        let mut errors = Vec::new();
+
+        if let Some(constructor) = &self.constructor {
+            validate_params(&mut errors, "constructor", &constructor.inputs);
+        }
+        for function in self.functions() {
+            let item = format!("function {}", function.name);
+            validate_name(&mut errors, &item, &function.name);
+            validate_params(&mut errors, &item, &function.inputs);
+            validate_params(&mut errors, &item, &function.outputs);
+        }
+        for event in self.events() {
+            let item = format!("event {}", event.name);
+            validate_name(&mut errors, &item, &event.name);
+            for param in &event.inputs {
+                let internal_type = param.internal_type();
+                validate_type(&mut errors, &item, &param.ty, &param.components, internal_type);
+            }
+
+            let indexed = event.inputs.iter().filter(|param| param.indexed).count();
+            let max = if event.anonymous { 4 } else { 3 };
+            if indexed > max {
+                let message = format!("too many indexed parameters: {indexed}, maximum is {max}");
+                errors.push(AbiError { item, message });
+            }
+        }
+        for error in self.errors() {
+            let item = format!("error {}", error.name);
+            validate_name(&mut errors, &item, &error.name);
+            validate_params(&mut errors, &item, &error.inputs);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn validate_name(errors: &mut Vec<AbiError>, item: &str, name: &str) {
+    if !is_valid_identifier(name) {
+        errors.push(AbiError { item: item.into(), message: format!("invalid name `{name}`") });
+    }
+}
+
+fn validate_params(errors: &mut Vec<AbiError>, item: &str, params: &[Param]) {
+    for param in params {
+        validate_type(errors, item, &param.ty, &param.components, param.internal_type());
+    }
+}
+
+fn validate_type(
+    errors: &mut Vec<AbiError>,
+    item: &str,
+    ty: &str,
+    components: &[Param],
+    internal_type: Option<&InternalType>,
+) {
+    let mut error = |message| errors.push(AbiError { item: item.into(), message });
+    let spec = match TypeSpecifier::parse(ty) {
+        Ok(spec) => spec,
+        Err(e) => return error(format!("invalid type `{ty}`: {e}")),
+    };
+
+    if spec.stem.as_root().map_or(false, |root| root.span() == "tuple") {
+        if components.is_empty() {
+            error(format!("tuple type `{ty}` has no components"));
+        }
+        validate_params(errors, item, components);
+    } else {
+        if let Err(e) = spec.try_basic_solidity() {
+            if !is_library_type(&spec, internal_type) {
+                error(format!("invalid type `{ty}`: {e}"));
+            }
+        }
+        if !components.is_empty() {
+            error(format!("non-tuple type `{ty}` has components"));
+        }
+    }
+}
+
+/// Returns `true` if `spec` is the name of the contract, enum or struct in
+/// `internal_type`, as emitted by `solc` for library functions.
+fn is_library_type(spec: &TypeSpecifier<'_>, internal_type: Option<&InternalType>) -> bool {
+    let Some(root) = spec.stem.as_root() else { return false };
+    let name = match internal_type {
+        Some(InternalType::Contract(name)) => name.as_str(),
+        Some(InternalType::Enum { ty, .. } | InternalType::Struct { ty, .. }) => ty.as_str(),
+        _ => return false,
+    };
+    name.split('[').next() == Some(root.span())
+}
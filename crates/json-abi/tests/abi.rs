@@ -47,6 +47,7 @@ fn abi_test(s: &str, path: &str, run_solc: bool) {
 
     assert_eq!(len, abi2.len());
     assert_eq!(abi1, abi2);
+    abi1.validate().unwrap();
 
     #[cfg(all(feature = "std", feature = "serde_json"))]
     load_test(path, &abi1);
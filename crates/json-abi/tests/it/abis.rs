@@ -595,3 +595,124 @@ fn fallback() {
 
     assert_ser_de!(JsonAbi, deserialized);
 }
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn from_combined_json() {
+    // `solc --combined-json abi,bin`; older versions stringify the ABI.
+    let json = r#"{
+        "contracts": {
+            "src/Counter.sol:Counter": {
+                "abi": [
+                    {
+                        "type": "function",
+                        "name": "increment",
+                        "inputs": [],
+                        "outputs": [],
+                        "stateMutability": "nonpayable"
+                    },
+                    {
+                        "type": "function",
+                        "name": "add",
+                        "inputs": [
+                            { "name": "amount", "type": "uint256", "internalType": "uint256" }
+                        ],
+                        "outputs": [
+                            { "name": "total", "type": "uint256", "internalType": "uint256" }
+                        ],
+                        "stateMutability": "nonpayable"
+                    },
+                    {
+                        "type": "event",
+                        "name": "Added",
+                        "inputs": [
+                            { "name": "by", "type": "address", "indexed": true, "internalType": "address" },
+                            { "name": "amount", "type": "uint256", "indexed": false, "internalType": "uint256" }
+                        ],
+                        "anonymous": false
+                    }
+                ],
+                "bin": "6080"
+            },
+            "src/Counter.sol:Legacy": {
+                "abi": "[{\"type\":\"event\",\"name\":\"Incremented\",\"inputs\":[],\"anonymous\":false}]",
+                "bin": "6080"
+            }
+        },
+        "version": "0.8.23+commit.f704f362.Linux.g++"
+    }"#;
+
+    let abi = JsonAbi::from_combined_json(json, "Counter").unwrap();
+    assert_eq!(abi.len(), 3);
+    assert_eq!(abi.function("increment").unwrap()[0].signature(), "increment()");
+    let add = &abi.function("add").unwrap()[0];
+    assert_eq!(add.signature(), "add(uint256)");
+    assert_eq!(add.inputs[0].name, "amount");
+    assert_eq!(add.outputs[0].name, "total");
+    assert_eq!(abi.event("Added").unwrap()[0].signature(), "Added(address,uint256)");
+    assert_eq!(abi, JsonAbi::from_combined_json(json, "src/Counter.sol:Counter").unwrap());
+
+    let legacy = JsonAbi::from_combined_json(json, "Legacy").unwrap();
+    assert_eq!(legacy.len(), 1);
+    assert_eq!(legacy.event("Incremented").unwrap()[0].signature(), "Incremented()");
+
+    let err = JsonAbi::from_combined_json(json, "Missing").unwrap_err();
+    assert!(err.to_string().contains("`Missing` not found"), "{err}");
+}
+
+#[test]
+fn validate() {
+    let json = r#"[
+        {
+            "type": "function",
+            "name": "foo",
+            "inputs": [
+                { "name": "a", "type": "uint7" },
+                { "name": "b", "type": "tuple[]", "components": [
+                    { "name": "c", "type": "MyStruct" }
+                ] }
+            ],
+            "outputs": [{ "name": "", "type": "tuple" }],
+            "stateMutability": "view"
+        },
+        {
+            "type": "event",
+            "name": "Bar",
+            "inputs": [
+                { "name": "a", "type": "uint256", "indexed": true },
+                { "name": "b", "type": "uint256", "indexed": true },
+                { "name": "c", "type": "uint256", "indexed": true },
+                { "name": "d", "type": "bytes33", "indexed": true }
+            ],
+            "anonymous": false
+        },
+        {
+            "type": "error",
+            "name": "",
+            "inputs": [{ "name": "a", "type": "address" }]
+        }
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(json).unwrap();
+    let errors = abi.validate().unwrap_err();
+    let errors = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            "function foo: invalid type `uint7`: invalid size for type: uint7",
+            "function foo: invalid type `MyStruct`: invalid type string: MyStruct",
+            "function foo: tuple type `tuple` has no components",
+            "event Bar: invalid type `bytes33`: invalid size for type: bytes33",
+            "event Bar: too many indexed parameters: 4, maximum is 3",
+            "error : invalid name ``",
+        ]
+    );
+
+    let mut abi = abi;
+    abi.functions.clear();
+    abi.errors.clear();
+    for event in abi.events_mut() {
+        event.anonymous = true;
+        event.inputs[3].ty = "bytes32".into();
+    }
+    abi.validate().unwrap();
+}
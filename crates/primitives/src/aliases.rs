@@ -86,3 +86,55 @@ pub type StorageValue = U256;
 /// Solidity contract functions are addressed using the first four bytes of the
 /// Keccak-256 hash of their signature.
 pub type Selector = FixedBytes<4>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "num-bigint")]
+    use num_bigint::BigUint;
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn num_bigint_roundtrip() {
+        let value = U256::MAX - U256::from(0x1234_5678_u64);
+        let big = BigUint::from(value);
+        assert_eq!(big.to_bytes_be(), value.to_be_bytes::<32>());
+        assert_eq!(U256::try_from(&big), Ok(value));
+        assert_eq!(U256::try_from(big), Ok(value));
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn num_bigint_overflow() {
+        let too_big = BigUint::from(U256::MAX) + 1u8;
+        assert!(U256::try_from(&too_big).is_err());
+    }
+
+    #[test]
+    fn bit_utils() {
+        assert_eq!(U256::ZERO.count_ones(), 0);
+        assert_eq!(U256::ZERO.count_zeros(), 256);
+        assert_eq!(U256::ZERO.leading_zeros(), 256);
+        assert_eq!(U256::ZERO.trailing_zeros(), 256);
+        assert_eq!(U256::ZERO.bit_len(), 0);
+        assert_eq!(U256::ZERO.byte_len(), 0);
+
+        assert_eq!(U256::MAX.count_ones(), 256);
+        assert_eq!(U256::MAX.count_zeros(), 0);
+        assert_eq!(U256::MAX.leading_zeros(), 0);
+        assert_eq!(U256::MAX.trailing_zeros(), 0);
+        assert_eq!(U256::MAX.bit_len(), 256);
+        assert_eq!(U256::MAX.byte_len(), 32);
+
+        for i in 0..256usize {
+            let pow2 = U256::from(1) << i;
+            assert_eq!(pow2.count_ones(), 1);
+            assert_eq!(pow2.leading_zeros(), 255 - i);
+            assert_eq!(pow2.trailing_zeros(), i);
+            assert_eq!(pow2.bit_len(), i + 1);
+            assert_eq!(pow2.bit_len(), 256 - pow2.leading_zeros());
+            assert_eq!(pow2.byte_len(), i / 8 + 1);
+            assert_eq!((pow2 - U256::from(1)).bit_len(), i);
+        }
+    }
+}
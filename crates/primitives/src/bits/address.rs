@@ -1,4 +1,4 @@
-use crate::{aliases::U160, utils::keccak256, wrap_fixed_bytes, FixedBytes};
+use crate::{aliases::U160, utils::keccak256, wrap_fixed_bytes, FixedBytes, ParseFixedBytesError};
 use alloc::{
     borrow::Borrow,
     string::{String, ToString},
@@ -11,6 +11,17 @@ pub enum AddressError {
     /// Error while decoding hex.
     Hex(hex::FromHexError),
 
+    /// The hex string does not have the expected length.
+    #[non_exhaustive]
+    InvalidLength {
+        /// The expected number of bytes.
+        expected: usize,
+        /// The number of hex digits in the input, excluding the prefix.
+        digits: usize,
+        /// Whether the input had a `0x` prefix.
+        prefixed: bool,
+    },
+
     /// Invalid ERC-55 checksum.
     InvalidChecksum,
 }
@@ -22,13 +33,25 @@ impl From<hex::FromHexError> for AddressError {
     }
 }
 
+impl From<ParseFixedBytesError> for AddressError {
+    #[inline]
+    fn from(value: ParseFixedBytesError) -> Self {
+        match value {
+            ParseFixedBytesError::Hex(err) => Self::Hex(err),
+            ParseFixedBytesError::InvalidLength { expected, digits, prefixed } => {
+                Self::InvalidLength { expected, digits, prefixed }
+            }
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for AddressError {
     #[inline]
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Hex(err) => Some(err),
-            Self::InvalidChecksum => None,
+            Self::InvalidLength { .. } | Self::InvalidChecksum => None,
         }
     }
 }
@@ -37,6 +60,9 @@ impl fmt::Display for AddressError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Hex(err) => err.fmt(f),
+            &Self::InvalidLength { expected, digits, prefixed } => {
+                ParseFixedBytesError::InvalidLength { expected, digits, prefixed }.fmt(f)
+            }
             Self::InvalidChecksum => f.write_str("Bad address checksum"),
         }
     }
@@ -441,11 +467,32 @@ impl Address {
 
     /// Instantiate by hashing public key bytes.
     ///
+    /// The public key must be an uncompressed secp256k1 point, either as the
+    /// raw 64-byte `x || y` coordinates or in SEC1 form, prefixed by `0x04`.
+    ///
     /// # Panics
     ///
-    /// If the input is not exactly 64 bytes
+    /// If the input is not exactly 64 bytes, or 65 bytes starting with `0x04`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, hex, Address};
+    /// let pubkey = hex!("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8");
+    /// let expected = address!("7e5f4552091a69125d5dfcb7b8c2659029395bdf");
+    /// assert_eq!(Address::from_raw_public_key(&pubkey), expected);
+    /// assert_eq!(Address::from_raw_public_key(&pubkey[1..]), expected);
+    /// ```
     pub fn from_raw_public_key(pubkey: &[u8]) -> Self {
-        assert_eq!(pubkey.len(), 64, "raw public key must be 64 bytes");
+        let pubkey = match pubkey {
+            [0x04, rest @ ..] if rest.len() == 64 => rest,
+            _ => pubkey,
+        };
+        assert_eq!(
+            pubkey.len(),
+            64,
+            "raw public key must be 64 bytes, or 65 bytes prefixed by 0x04"
+        );
         let digest = keccak256(pubkey);
         Self::from_slice(&digest[12..])
     }
@@ -557,6 +604,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_checksummed_invalid_length() {
+        let err = Address::parse_checksummed("0x52908400098527886E0F7030069857D2E4169E", None)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AddressError::InvalidLength { expected: 20, digits: 38, prefixed: true }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "invalid hex string length: expected 20 bytes, got 19 (with `0x` prefix)"
+        );
+    }
+
     // https://eips.ethereum.org/EIPS/eip-55
     #[test]
     fn checksum() {
@@ -661,7 +722,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "rlp", feature = "arbitrary"))]
+    #[cfg(all(feature = "rlp", feature = "proptest"))]
     #[cfg_attr(miri, ignore = "doesn't run in isolation and would take too long")]
     fn create_correctness() {
         fn create_slow(address: &Address, nonce: u64) -> Address {
@@ -752,5 +813,16 @@ mod tests {
         let pubkey_bytes = hex::decode("76698beebe8ee5c74d8cc50ab84ac301ee8f10af6f28d0ffd6adf4d6d3b9b762d46ca56d3dad2ce13213a6f42278dabbb53259f2d92681ea6a0b98197a719be3").unwrap();
 
         assert_eq!(Address::from_raw_public_key(&pubkey_bytes), addr);
+
+        let prefixed = [&[0x04][..], &pubkey_bytes].concat();
+        assert_eq!(Address::from_raw_public_key(&prefixed), addr);
+    }
+
+    #[test]
+    #[should_panic = "raw public key must be 64 bytes"]
+    fn test_raw_public_key_invalid_prefix() {
+        let mut pubkey = [0; 65];
+        pubkey[0] = 0x02;
+        Address::from_raw_public_key(&pubkey);
     }
 }
@@ -26,7 +26,8 @@ use derive_more::{Deref, DerefMut, From, Index, IndexMut, IntoIterator};
     IndexMut,
     IntoIterator,
 )]
-#[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary))]
+#[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary))]
+#[cfg_attr(feature = "proptest", derive(proptest_derive::Arbitrary))]
 #[repr(transparent)]
 pub struct FixedBytes<const N: usize>(#[into_iterator(owned, ref, ref_mut)] pub [u8; N]);
 
@@ -302,16 +303,96 @@ impl<const N: usize> ops::BitXorAssign for FixedBytes<N> {
 }
 
 impl<const N: usize> str::FromStr for FixedBytes<N> {
-    type Err = hex::FromHexError;
+    type Err = ParseFixedBytesError;
 
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (prefixed, hex) = match s.strip_prefix("0x") {
+            Some(hex) => (true, hex),
+            None => (false, s),
+        };
+        if hex.len() != N * 2 {
+            return Err(ParseFixedBytesError::InvalidLength {
+                expected: N,
+                digits: hex.len(),
+                prefixed,
+            });
+        }
         let mut buf = [0u8; N];
-        hex::decode_to_slice(s, &mut buf)?;
+        hex::decode_to_slice(hex, &mut buf)?;
         Ok(Self(buf))
     }
 }
 
+/// Error returned when parsing a [`FixedBytes`], or a type wrapping it like
+/// [`Address`](crate::Address), from a hex string.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ParseFixedBytesError {
+    /// Error while decoding hex.
+    Hex(hex::FromHexError),
+
+    /// The hex string does not have the expected length.
+    #[non_exhaustive]
+    InvalidLength {
+        /// The expected number of bytes.
+        expected: usize,
+        /// The number of hex digits in the input, excluding the prefix.
+        digits: usize,
+        /// Whether the input had a `0x` prefix.
+        prefixed: bool,
+    },
+}
+
+impl From<hex::FromHexError> for ParseFixedBytesError {
+    #[inline]
+    fn from(value: hex::FromHexError) -> Self {
+        Self::Hex(value)
+    }
+}
+
+impl From<ParseFixedBytesError> for hex::FromHexError {
+    #[inline]
+    fn from(value: ParseFixedBytesError) -> Self {
+        match value {
+            ParseFixedBytesError::Hex(err) => err,
+            ParseFixedBytesError::InvalidLength { digits, .. } if digits % 2 != 0 => {
+                Self::OddLength
+            }
+            ParseFixedBytesError::InvalidLength { .. } => Self::InvalidStringLength,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFixedBytesError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Hex(err) => Some(err),
+            Self::InvalidLength { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseFixedBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Hex(err) => err.fmt(f),
+            Self::InvalidLength { expected, digits, prefixed } => {
+                let half = if digits % 2 == 0 { "" } else { ".5" };
+                let prefix = if prefixed { "with" } else { "without" };
+                write!(
+                    f,
+                    "invalid hex string length: expected {expected} bytes, got {}{half} \
+                     ({prefix} `0x` prefix)",
+                    digits / 2
+                )
+            }
+        }
+    }
+}
+
 #[cfg(feature = "rand")]
 impl<const N: usize> rand::distributions::Distribution<FixedBytes<N>>
     for rand::distributions::Standard
@@ -584,6 +665,38 @@ impl<const N: usize> FixedBytes<N> {
         ret
     }
 
+    /// Returns a new `FixedBytes` with the order of all `N` bytes reversed.
+    ///
+    /// This converts between big-endian and little-endian representations,
+    /// which is useful when importing data from non-EVM sources.
+    ///
+    /// This is not named `reverse`, as it would shadow the in-place
+    /// [`slice::reverse`] that is reachable through `Deref`.
+    ///
+    /// [`U256`](crate::U256) is defined in `ruint`, so it cannot have an
+    /// inherent `swap_bytes` method here. To byte-swap one, go through its byte
+    /// representation: `U256::from_le_bytes(x.to_be_bytes::<32>())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::fixed_bytes;
+    /// let bytes = fixed_bytes!("01020304");
+    /// assert_eq!(bytes.reverse_bytes(), fixed_bytes!("04030201"));
+    /// ```
+    #[inline]
+    #[doc(alias = "reverse")]
+    #[doc(alias = "swap_bytes")]
+    pub const fn reverse_bytes(self) -> Self {
+        let mut ret = Self::ZERO;
+        let mut i = 0;
+        while i < N {
+            ret.0[i] = self.0[N - 1 - i];
+            i += 1;
+        }
+        ret
+    }
+
     fn fmt_hex<const UPPER: bool>(&self, f: &mut fmt::Formatter<'_>, prefix: bool) -> fmt::Result {
         let mut buf = hex::Buffer::<N, true>::new();
         let s = if UPPER { buf.format_upper(self) } else { buf.format(self) };
@@ -595,6 +708,7 @@ impl<const N: usize> FixedBytes<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{B256, U256};
 
     macro_rules! test_fmt {
         ($($fmt:literal, $hex:literal => $expected:literal;)+) => {$(
@@ -605,6 +719,56 @@ mod tests {
         )+};
     }
 
+    #[test]
+    fn parse_invalid_length() {
+        use crate::Address;
+
+        fn err<T: str::FromStr<Err = ParseFixedBytesError>>(s: &str) -> alloc::string::String {
+            s.parse::<T>().err().unwrap().to_string()
+        }
+
+        // too short, odd length
+        assert_eq!(
+            "0x123".parse::<Address>(),
+            Err(ParseFixedBytesError::InvalidLength { expected: 20, digits: 3, prefixed: true })
+        );
+        assert_eq!(
+            err::<Address>("0x123"),
+            "invalid hex string length: expected 20 bytes, got 1.5 (with `0x` prefix)"
+        );
+
+        // too long
+        let long = "00".repeat(33);
+        assert_eq!(
+            err::<B256>(&long),
+            "invalid hex string length: expected 32 bytes, got 33 (without `0x` prefix)"
+        );
+
+        // missing prefix
+        let addr = "d8da6bf26964af9d7eed9e03e53415d37aa96045";
+        assert_eq!(
+            addr.parse::<Address>().unwrap(),
+            format!("0x{addr}").parse::<Address>().unwrap()
+        );
+        assert_eq!(
+            err::<Address>(&addr[1..]),
+            "invalid hex string length: expected 20 bytes, got 19.5 (without `0x` prefix)"
+        );
+
+        // correct length, invalid characters
+        let invalid = format!("0x{}", "zz".repeat(32));
+        assert!(matches!(
+            invalid.parse::<B256>(),
+            Err(ParseFixedBytesError::Hex(hex::FromHexError::InvalidHexCharacter { c: 'z', .. }))
+        ));
+
+        // compatibility with `hex::FromHexError`
+        let e = "0x123".parse::<B256>().unwrap_err();
+        assert_eq!(hex::FromHexError::from(e), hex::FromHexError::OddLength);
+        let e = "0x1234".parse::<B256>().unwrap_err();
+        assert_eq!(hex::FromHexError::from(e), hex::FromHexError::InvalidStringLength);
+    }
+
     #[test]
     fn concat_const() {
         const A: FixedBytes<2> = fixed_bytes!("0123");
@@ -615,6 +779,25 @@ mod tests {
         assert_eq!(ACTUAL, EXPECTED);
     }
 
+    #[test]
+    fn reverse_bytes() {
+        const A: FixedBytes<4> = fixed_bytes!("01234567");
+        const REVERSED: FixedBytes<4> = A.reverse_bytes();
+        assert_eq!(REVERSED, fixed_bytes!("67452301"));
+        assert_eq!(REVERSED.reverse_bytes(), A);
+
+        let b = B256::with_last_byte(1);
+        let mut expected = B256::ZERO;
+        expected[0] = 1;
+        assert_eq!(b.reverse_bytes(), expected);
+        assert_eq!(b.reverse_bytes().reverse_bytes(), b);
+
+        let x = U256::from(0x0102_0304_u64) << 100_usize;
+        let swapped = U256::from_le_bytes(x.to_be_bytes::<32>());
+        assert_eq!(B256::from(swapped), B256::from(x).reverse_bytes());
+        assert_eq!(U256::from_le_bytes(swapped.to_be_bytes::<32>()), x);
+    }
+
     #[test]
     fn display() {
         test_fmt! {
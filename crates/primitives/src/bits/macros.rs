@@ -177,6 +177,7 @@ macro_rules! wrap_fixed_bytes {
         $crate::impl_rlp!($name, $n);
         $crate::impl_serde!($name);
         $crate::impl_arbitrary!($name, $n);
+        $crate::impl_proptest!($name, $n);
         $crate::impl_ssz_fixed_len!($name, $n);
         $crate::impl_rand!($name);
 
@@ -609,7 +610,21 @@ macro_rules! impl_arbitrary {
                 <$crate::FixedBytes<$n> as $crate::private::arbitrary::Arbitrary>::size_hint(depth)
             }
         }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "arbitrary"))]
+macro_rules! impl_arbitrary {
+    ($t:ty, $n:literal) => {};
+}
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "proptest")]
+macro_rules! impl_proptest {
+    ($t:ty, $n:literal) => {
         impl $crate::private::proptest::arbitrary::Arbitrary for $t {
             type Parameters = <$crate::FixedBytes<$n> as $crate::private::proptest::arbitrary::Arbitrary>::Parameters;
             type Strategy = $crate::private::proptest::strategy::Map<
@@ -636,8 +651,8 @@ macro_rules! impl_arbitrary {
 
 #[doc(hidden)]
 #[macro_export]
-#[cfg(not(feature = "arbitrary"))]
-macro_rules! impl_arbitrary {
+#[cfg(not(feature = "proptest"))]
+macro_rules! impl_proptest {
     ($t:ty, $n:literal) => {};
 }
 
@@ -8,7 +8,7 @@ mod bloom;
 pub use bloom::{Bloom, BloomInput, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES};
 
 mod fixed;
-pub use fixed::FixedBytes;
+pub use fixed::{FixedBytes, ParseFixedBytesError};
 
 mod function;
 pub use function::Function;
@@ -301,6 +301,26 @@ impl Bytes {
     }
 
     /// Returns a slice of self that is equivalent to the given `subset`.
+    ///
+    /// This does not copy: the returned `Bytes` shares the same underlying
+    /// buffer as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `subset` is not contained within `self`. An empty `subset`
+    /// always returns an empty `Bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::Bytes;
+    ///
+    /// let bytes = Bytes::from(vec![1, 2, 3, 4]);
+    /// let subset = &bytes[1..3];
+    /// let slice = bytes.slice_ref(subset);
+    /// assert_eq!(slice, [2, 3][..]);
+    /// assert_eq!(slice.as_ptr(), subset.as_ptr());
+    /// ```
     #[inline]
     pub fn slice_ref(&self, subset: &[u8]) -> Self {
         Self(self.0.slice_ref(subset))
@@ -339,7 +359,7 @@ impl<'a> arbitrary::Arbitrary<'a> for Bytes {
     }
 }
 
-#[cfg(feature = "arbitrary")]
+#[cfg(feature = "proptest")]
 impl proptest::arbitrary::Arbitrary for Bytes {
     type Parameters = proptest::arbitrary::ParamsFor<Vec<u8>>;
     type Strategy = proptest::arbitrary::Mapped<Vec<u8>, Self>;
@@ -381,4 +401,28 @@ mod tests {
         assert_eq!(format!("{b:X}"), "0x0123456789ABCDEF");
         assert_eq!(format!("{b:#X}"), "0x0123456789ABCDEF");
     }
+
+    #[test]
+    fn slice_ref() {
+        let b = Bytes::from(vec![1, 2, 3, 4, 5]);
+        let subset = &b[1..4];
+        let slice = b.slice_ref(subset);
+        assert_eq!(slice, [2, 3, 4][..]);
+        assert_eq!(slice.as_ptr(), subset.as_ptr());
+        assert_eq!(slice.as_ptr() as usize - b.as_ptr() as usize, 1);
+
+        // slicing again still shares the original buffer
+        let inner = slice.slice_ref(&slice[1..]);
+        assert_eq!(inner.as_ptr(), b[2..].as_ptr());
+
+        assert!(b.slice_ref(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_ref_out_of_bounds() {
+        let b = Bytes::from(vec![1, 2, 3]);
+        let other = [1, 2, 3];
+        let _ = b.slice_ref(&other);
+    }
 }
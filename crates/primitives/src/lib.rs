@@ -24,6 +24,9 @@ use tiny_keccak as _;
 #[cfg(feature = "postgres")]
 pub mod postgres;
 
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
 pub mod aliases;
 #[doc(no_inline)]
 pub use aliases::{
@@ -36,7 +39,7 @@ pub use aliases::{
 mod bits;
 pub use bits::{
     Address, AddressChecksumBuffer, AddressError, Bloom, BloomInput, FixedBytes, Function,
-    BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
+    ParseFixedBytesError, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
 };
 
 #[path = "bytes/mod.rs"]
@@ -127,5 +130,8 @@ pub mod private {
     pub use serde;
 
     #[cfg(feature = "arbitrary")]
-    pub use {arbitrary, derive_arbitrary, proptest, proptest_derive};
+    pub use {arbitrary, derive_arbitrary};
+
+    #[cfg(feature = "proptest")]
+    pub use {proptest, proptest_derive};
 }
@@ -97,14 +97,9 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<i128> for Signed<BITS, LIMBS
             return Self::try_from(u);
         }
 
-        // This is a bit messy :(
-        let tc = (!u).wrapping_add(1);
-        let stc = Uint::<128, 2>::saturating_from(tc);
-        let (num, overflow) = Uint::<BITS, LIMBS>::overflowing_from_limbs_slice(stc.as_limbs());
-        if overflow {
-            return Err(BigIntConversionError);
-        }
-        Ok(Self(twos_complement(num)))
+        let abs = (!u).wrapping_add(1);
+        let abs = Uint::<BITS, LIMBS>::try_from(abs).map_err(|_| BigIntConversionError)?;
+        Self::checked_from_sign_and_abs(Sign::Negative, abs).ok_or(BigIntConversionError)
     }
 }
 
@@ -179,8 +174,8 @@ macro_rules! impl_conversions {
                     }
 
                     let abs = (!uint).wrapping_add(1);
-                    let tc = twos_complement(Uint::<BITS, LIMBS>::from(abs));
-                    Ok(Self(tc))
+                    let abs = Uint::<BITS, LIMBS>::try_from(abs).map_err(|_| BigIntConversionError)?;
+                    Signed::checked_from_sign_and_abs(Sign::Negative, abs).ok_or(BigIntConversionError)
                 }
             }
 
@@ -51,7 +51,8 @@ use ruint::{BaseConvertError, Uint};
 /// assert_eq!(I256::MINUS_ONE, I256::unchecked_from(-1));
 /// ```
 #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary))]
+#[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary))]
+#[cfg_attr(feature = "proptest", derive(proptest_derive::Arbitrary))]
 pub struct Signed<const BITS: usize, const LIMBS: usize>(pub(crate) Uint<BITS, LIMBS>);
 
 // formatting
@@ -224,6 +225,21 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         matches!(self.sign(), Sign::Negative)
     }
 
+    /// Returns a number representing the sign of `self`:
+    /// - `0` if the number is zero,
+    /// - `1` if the number is positive,
+    /// - `-1` if the number is negative.
+    #[inline]
+    pub const fn signum(&self) -> i8 {
+        if self.is_zero() {
+            0
+        } else if self.is_negative() {
+            -1
+        } else {
+            1
+        }
+    }
+
     /// Returns the number of ones in the binary representation of `self`.
     #[inline]
     pub fn count_ones(&self) -> usize {
@@ -584,6 +600,10 @@ mod tests {
                         <$i_struct>::try_from(<$i>::MAX).unwrap_err(),
                         BigIntConversionError,
                     );
+                    assert_eq!(
+                        <$i_struct>::try_from(<$i>::MIN).unwrap_err(),
+                        BigIntConversionError,
+                    );
                 }
             };
 
@@ -605,6 +625,11 @@ mod tests {
         assert_eq!(I1::try_from(1u8), Err(BigIntConversionError));
         assert_eq!(I1::try_from(1i8), Err(BigIntConversionError));
         assert_eq!(I1::try_from(-1), Ok(I1::MINUS_ONE));
+        assert_eq!(I1::try_from(-2), Err(BigIntConversionError));
+        assert_eq!(I8::try_from(-128i64), Ok(I8::MIN));
+        assert_eq!(I8::try_from(-129i64), Err(BigIntConversionError));
+        assert_eq!(I8::try_from(-200i128), Err(BigIntConversionError));
+        assert_eq!(I8::try_from(128u8), Err(BigIntConversionError));
 
         run_test!(I96, U96);
         run_test!(I128, U128);
@@ -771,6 +796,14 @@ mod tests {
         let m = I1::MINUS_ONE;
         assert_eq!(format!("{z} {o} {m}"), "0 0 -1");
 
+        let min = "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+        assert_eq!(I256::MIN.to_string(), min);
+        assert_eq!(I256::MIN.unsigned_abs().to_string(), &min[1..]);
+        assert_eq!(
+            U256::MAX.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+
         run_test!(I96, U96);
         run_test!(I128, U128);
         run_test!(I160, U160);
@@ -806,6 +839,12 @@ mod tests {
                 assert!(!<$i_struct>::ZERO.is_positive());
                 assert!(!<$i_struct>::ZERO.is_negative());
                 assert!(<$i_struct>::ZERO.is_zero());
+
+                assert_eq!(<$i_struct>::MAX.signum(), 1);
+                assert_eq!(<$i_struct>::ONE.signum(), 1);
+                assert_eq!(<$i_struct>::MIN.signum(), -1);
+                assert_eq!(<$i_struct>::MINUS_ONE.signum(), -1);
+                assert_eq!(<$i_struct>::ZERO.signum(), 0);
             };
         }
 
@@ -840,6 +879,13 @@ mod tests {
                 assert_eq!(<$i_struct>::MAX.abs(), <$i_struct>::MAX);
                 assert_eq!((-<$i_struct>::MAX).abs(), <$i_struct>::MAX);
                 assert_eq!(<$i_struct>::MIN.checked_abs(), None);
+
+                assert_eq!(negative.unsigned_abs(), <$u_struct>::from(27182818284590u64));
+                assert_eq!(<$i_struct>::MINUS_ONE.unsigned_abs(), <$u_struct>::from(1));
+                assert_eq!(
+                    <$i_struct>::MIN.unsigned_abs(),
+                    <$u_struct>::from(1) << (<$u_struct>::BITS - 1)
+                );
             };
         }
 
@@ -0,0 +1,96 @@
+//! [`proptest`] strategies for the primitive types.
+//!
+//! All the primitive types implement [`Arbitrary`](proptest::arbitrary::Arbitrary),
+//! which generates values uniformly over their full range. The strategies in
+//! this module instead generate edge values, such as zero and the maximum
+//! value, with a higher probability, as these are unlikely to be generated
+//! otherwise.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{strategies, U256};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(x in strategies::uint::<256, 4>())| {
+//!     prop_assert_eq!(U256::from_be_bytes(x.to_be_bytes::<32>()), x);
+//! });
+//! ```
+
+use crate::{Address, FixedBytes, Signed};
+use proptest::{
+    arbitrary::any,
+    prop_oneof,
+    strategy::{Just, Strategy},
+};
+use ruint::Uint;
+
+/// Returns a strategy for [`Uint`] which generates zero and [`MAX`](Uint::MAX)
+/// with a higher probability.
+pub fn uint<const BITS: usize, const LIMBS: usize>() -> impl Strategy<Value = Uint<BITS, LIMBS>> {
+    prop_oneof![
+        1 => Just(Uint::ZERO),
+        1 => Just(Uint::MAX),
+        8 => any::<Uint<BITS, LIMBS>>(),
+    ]
+}
+
+/// Returns a strategy for [`Signed`] which generates zero, [`MIN`](Signed::MIN)
+/// and [`MAX`](Signed::MAX) with a higher probability.
+pub fn signed<const BITS: usize, const LIMBS: usize>() -> impl Strategy<Value = Signed<BITS, LIMBS>>
+{
+    prop_oneof![
+        1 => Just(Signed::ZERO),
+        1 => Just(Signed::MIN),
+        1 => Just(Signed::MAX),
+        7 => uint::<BITS, LIMBS>().prop_map(Signed::from_raw),
+    ]
+}
+
+/// Returns a strategy for [`FixedBytes`] which generates all zero and all
+/// `0xff` bytes with a higher probability.
+pub fn fixed_bytes<const N: usize>() -> impl Strategy<Value = FixedBytes<N>> {
+    prop_oneof![
+        1 => Just(FixedBytes::ZERO),
+        1 => Just(FixedBytes::repeat_byte(0xff)),
+        8 => any::<FixedBytes<N>>(),
+    ]
+}
+
+/// Returns a strategy for [`Address`] which generates the zero address and
+/// the all `0xff` address with a higher probability.
+pub fn address() -> impl Strategy<Value = Address> {
+    fixed_bytes::<20>().prop_map(Address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I256, U256};
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn u256_roundtrip(x in uint::<256, 4>()) {
+            prop_assert_eq!(U256::from_be_bytes(x.to_be_bytes::<32>()), x);
+            prop_assert_eq!(U256::from_le_bytes(x.to_le_bytes::<32>()), x);
+            prop_assert_eq!(U256::try_from_be_slice(&x.to_be_bytes_trimmed_vec()), Some(x));
+            prop_assert_eq!(x.to_string().parse::<U256>(), Ok(x));
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn i256_roundtrip(x in signed::<256, 4>()) {
+            prop_assert_eq!(x.to_string().parse::<I256>(), Ok(x));
+            prop_assert_eq!(I256::from_raw(x.into_raw()), x);
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn address_roundtrip(address in address()) {
+            prop_assert_eq!(address.to_checksum(None).parse::<Address>(), Ok(address));
+            prop_assert!(address == Address::from_word(address.into_word()));
+        }
+    }
+}
@@ -113,6 +113,9 @@ pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> B256 {
 /// Note that the "native-keccak" feature is not supported for this struct, and will default to the
 /// [`tiny_keccak`] implementation.
 ///
+/// With the "std" feature, this also implements [`std::io::Write`], which allows hashing a reader
+/// with [`std::io::copy`].
+///
 /// [`Keccak-256`]: https://en.wikipedia.org/wiki/SHA-3
 #[derive(Clone)]
 pub struct Keccak256 {
@@ -200,6 +203,20 @@ impl Keccak256 {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::io::Write for Keccak256 {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,4 +259,15 @@ mod tests {
         unsafe { hasher.finalize_into_raw(hash.as_mut_ptr()) };
         assert_eq!(hash, expected);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn keccak256_hasher_io_write() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+
+        let mut hasher = Keccak256::new();
+        let copied = std::io::copy(&mut &data[..], &mut hasher).unwrap();
+        assert_eq!(copied, data.len() as u64);
+        assert_eq!(hasher.finalize(), keccak256(&data));
+    }
 }
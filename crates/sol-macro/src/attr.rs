@@ -1,7 +1,9 @@
 use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{punctuated::Punctuated, Attribute, Error, LitBool, LitStr, Path, Result, Token};
+use syn::{
+    punctuated::Punctuated, Attribute, Error, LitBool, LitInt, LitStr, Meta, Path, Result, Token,
+};
 
 const DUPLICATE_ERROR: &str = "duplicate attribute";
 const UNKNOWN_ERROR: &str = "unknown `sol` attribute";
@@ -55,6 +57,52 @@ pub fn derives_mapped(attrs: &[Attribute]) -> impl Iterator<Item = Path> + '_ {
     })
 }
 
+/// Removes `Debug` from all the `#[derive(...)]` attributes, including the ones
+/// nested in `#[cfg_attr(...)]`. Attributes left empty are removed.
+pub fn strip_derive_debug(attrs: &mut Vec<Attribute>) {
+    attrs.retain_mut(|attr| strip_derive_debug_meta(&mut attr.meta));
+}
+
+/// Returns `false` if the meta item is now empty and should be removed.
+fn strip_derive_debug_meta(meta: &mut Meta) -> bool {
+    let Meta::List(list) = meta else {
+        return true;
+    };
+
+    if list.path.is_ident("derive") {
+        let Ok(paths) = list.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+        else {
+            return true;
+        };
+        let len = paths.len();
+        let paths = paths
+            .into_iter()
+            .filter(|path| path.segments.last().map_or(true, |last| last.ident != "Debug"))
+            .collect::<Vec<_>>();
+        if paths.len() == len {
+            return true;
+        }
+        list.tokens = quote!(#(#paths),*);
+        !paths.is_empty()
+    } else if list.path.is_ident("cfg_attr") {
+        let Ok(metas) = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        else {
+            return true;
+        };
+        let mut metas = metas.into_iter();
+        let Some(predicate) = metas.next() else {
+            return true;
+        };
+        let metas = metas
+            .filter_map(|mut meta| strip_derive_debug_meta(&mut meta).then_some(meta))
+            .collect::<Vec<_>>();
+        list.tokens = quote!(#predicate, #(#metas),*);
+        !metas.is_empty()
+    } else {
+        true
+    }
+}
+
 // When adding a new attribute:
 // 1. add a field to this struct,
 // 2. add a match arm in the `parse` function below,
@@ -70,6 +118,10 @@ pub struct SolAttrs {
     pub extra_methods: Option<bool>,
     pub docs: Option<bool>,
     pub abi: Option<bool>,
+    pub rlp: Option<bool>,
+    pub fixed_bytes_as_array: Option<bool>,
+    pub copy: Option<bool>,
+    pub debug: Option<bool>,
 
     // TODO: Implement
     pub rename: Option<LitStr>,
@@ -80,6 +132,8 @@ pub struct SolAttrs {
     pub deployed_bytecode: Option<LitStr>,
 
     pub type_check: Option<LitStr>,
+
+    pub max_bytes: Option<usize>,
 }
 
 impl SolAttrs {
@@ -123,6 +177,9 @@ impl SolAttrs {
                 // `path = "<str>"`
                 let lit = || meta.value()?.parse::<LitStr>();
 
+                // `path = <int>`
+                let int = || meta.value()?.parse::<LitInt>()?.base10_parse::<usize>();
+
                 // `path = "0x<hex>"`
                 let bytes = || {
                     let lit = lit()?;
@@ -142,6 +199,10 @@ impl SolAttrs {
                     extra_methods => bool()?,
                     docs => bool()?,
                     abi => bool()?,
+                    rlp => bool()?,
+                    fixed_bytes_as_array => bool()?,
+                    copy => bool()?,
+                    debug => bool()?,
 
                     rename => lit()?,
                     rename_all => CasingStyle::from_lit(&lit()?)?,
@@ -150,6 +211,8 @@ impl SolAttrs {
                     deployed_bytecode => bytes()?,
 
                     type_check => lit()?,
+
+                    max_bytes => int()?,
                 };
                 Ok(())
             })?;
@@ -307,6 +370,24 @@ mod tests {
             #[sol(abi)] => Ok(sol_attrs! { abi: true }),
             #[sol(abi = true)] => Ok(sol_attrs! { abi: true }),
             #[sol(abi = false)] => Ok(sol_attrs! { abi: false }),
+
+            #[sol(rlp)] => Ok(sol_attrs! { rlp: true }),
+            #[sol(rlp = true)] => Ok(sol_attrs! { rlp: true }),
+            #[sol(rlp = false)] => Ok(sol_attrs! { rlp: false }),
+
+            #[sol(fixed_bytes_as_array)] => Ok(sol_attrs! { fixed_bytes_as_array: true }),
+            #[sol(fixed_bytes_as_array = true)] => Ok(sol_attrs! { fixed_bytes_as_array: true }),
+            #[sol(fixed_bytes_as_array = false)] => Ok(sol_attrs! { fixed_bytes_as_array: false }),
+
+            #[sol(copy)] => Ok(sol_attrs! { copy: true }),
+            #[sol(copy = true)] => Ok(sol_attrs! { copy: true }),
+            #[sol(copy = false)] => Ok(sol_attrs! { copy: false }),
+            #[sol(copy)] #[sol(copy = false)] => Err(DUPLICATE_ERROR),
+
+            #[sol(debug)] => Ok(sol_attrs! { debug: true }),
+            #[sol(debug = true)] => Ok(sol_attrs! { debug: true }),
+            #[sol(debug = false)] => Ok(sol_attrs! { debug: false }),
+            #[sol(debug)] #[sol(debug = false)] => Err(DUPLICATE_ERROR),
         }
 
         rename {
@@ -331,5 +412,32 @@ mod tests {
             #[sol(type_check = "my_function")] => Ok(sol_attrs! {type_check: parse_quote!("my_function")} ),
             #[sol(type_check = "my_function1")] #[sol(type_check = "my_function2")] => Err(DUPLICATE_ERROR),
         }
+
+        max_bytes {
+            #[sol(max_bytes = 65536)] => Ok(sol_attrs! { max_bytes: 65536 }),
+            #[sol(max_bytes = 0x20)] => Ok(sol_attrs! { max_bytes: 32 }),
+            #[sol(max_bytes = "1")] => Err("expected integer literal"),
+            #[sol(max_bytes = -1)] => Err("invalid digit found in string"),
+            #[sol(max_bytes = 1)] #[sol(max_bytes = 2)] => Err(DUPLICATE_ERROR),
+        }
+    }
+
+    #[test]
+    fn strip_derive_debug() {
+        let mut attrs: Vec<Attribute> = vec![
+            parse_quote!(#[derive(Debug)]),
+            parse_quote!(#[derive(Clone, core::fmt::Debug, PartialEq)]),
+            parse_quote!(#[cfg_attr(feature = "a", derive(Debug))]),
+            parse_quote!(#[cfg_attr(feature = "b", derive(Debug, Hash), doc = "b")]),
+            parse_quote!(#[cfg_attr(feature = "c", cfg_attr(test, derive(Debug)))]),
+            parse_quote!(#[doc = "Debug"]),
+        ];
+        super::strip_derive_debug(&mut attrs);
+        let expected: Vec<Attribute> = vec![
+            parse_quote!(#[derive(Clone, PartialEq)]),
+            parse_quote!(#[cfg_attr(feature = "b", derive(Hash), doc = "b")]),
+            parse_quote!(#[doc = "Debug"]),
+        ];
+        assert_eq!(attrs, expected);
     }
 }
@@ -1,12 +1,12 @@
 //! [`ItemContract`] expansion.
 
-use super::{ty, ExpCtxt};
+use super::{anon_name, ty, ExpCtxt};
 use crate::{attr, utils::ExprArray};
 use ast::{Item, ItemContract, ItemError, ItemEvent, ItemFunction, SolIdent, Spanned};
 use heck::ToSnakeCase;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use syn::{parse_quote, Attribute, Result};
+use syn::{ext::IdentExt, parse_quote, Attribute, Result};
 
 /// Expands an [`ItemContract`]:
 ///
@@ -18,6 +18,10 @@ use syn::{parse_quote, Attribute, Result};
 ///         ...
 ///    }
 ///
+///     pub fn decode_call(data: &[u8], validate: bool) -> Result<#{name}Calls> {
+///         ...
+///     }
+///
 ///     pub enum #{name}Errors {
 ///         ...
 ///    }
@@ -25,6 +29,10 @@ use syn::{parse_quote, Attribute, Result};
 ///     pub enum #{name}Events {
 ///         ...
 ///    }
+///
+///     pub fn decode_event(log: &Log, validate: bool) -> Result<#{name}Events> {
+///         ...
+///     }
 /// }
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenStream> {
@@ -35,17 +43,20 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
 
+    let has_bytecode = sol_attrs.bytecode.is_some();
     let bytecode = sol_attrs.bytecode.map(|lit| {
         let name = Ident::new("BYTECODE", lit.span());
+        let doc = docs.then(|| attr::mk_doc("The creation / init code of the contract."));
         quote! {
-            /// The creation / init code of the contract.
+            #doc
             pub static #name: ::alloy_sol_types::private::Bytes = ::alloy_sol_types::private::bytes!(#lit);
         }
     });
     let deployed_bytecode = sol_attrs.deployed_bytecode.map(|lit| {
         let name = Ident::new("DEPLOYED_BYTECODE", lit.span());
+        let doc = docs.then(|| attr::mk_doc("The runtime bytecode of the contract."));
         quote! {
-            /// The runtime bytecode of the contract.
+            #doc
             pub static #name: ::alloy_sol_types::private::Bytes = ::alloy_sol_types::private::bytes!(#lit);
         }
     });
@@ -110,27 +121,40 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
         }
     }
 
-    let enum_expander = CallLikeExpander { cx, contract_name: name.clone(), extra_methods };
+    let deploy_builder = has_bytecode.then(|| expand_deploy_builder(constructor, docs));
+
+    let enum_expander =
+        CallLikeExpander { cx, contract_name: name.clone(), extra_methods, debug: sol_attrs.debug };
 
     let functions_enum = (!functions.is_empty()).then(|| {
         let mut attrs = item_attrs.clone();
-        let doc_str = format!("Container for all the [`{name}`](self) function calls.");
-        attrs.push(parse_quote!(#[doc = #doc_str]));
-        enum_expander.expand(ToExpand::Functions(&functions), attrs)
+        if docs {
+            let doc_str = format!("Container for all the [`{name}`](self) function calls.");
+            attrs.push(parse_quote!(#[doc = #doc_str]));
+        }
+        let decode = expand_decode_call(name, docs);
+        let enum_ = enum_expander.expand(ToExpand::Functions(&functions), attrs);
+        quote!(#enum_ #decode)
     });
 
     let errors_enum = (!errors.is_empty()).then(|| {
         let mut attrs = item_attrs.clone();
-        let doc_str = format!("Container for all the [`{name}`](self) custom errors.");
-        attrs.push(parse_quote!(#[doc = #doc_str]));
+        if docs {
+            let doc_str = format!("Container for all the [`{name}`](self) custom errors.");
+            attrs.push(parse_quote!(#[doc = #doc_str]));
+        }
         enum_expander.expand(ToExpand::Errors(&errors), attrs)
     });
 
     let events_enum = (!events.is_empty()).then(|| {
         let mut attrs = item_attrs;
-        let doc_str = format!("Container for all the [`{name}`](self) events.");
-        attrs.push(parse_quote!(#[doc = #doc_str]));
-        enum_expander.expand(ToExpand::Events(&events), attrs)
+        if docs {
+            let doc_str = format!("Container for all the [`{name}`](self) events.");
+            attrs.push(parse_quote!(#[doc = #doc_str]));
+        }
+        let decode = expand_decode_event(name, docs);
+        let enum_ = enum_expander.expand(ToExpand::Events(&events), attrs);
+        quote!(#enum_ #decode)
     });
 
     let mod_descr_doc = (docs && attr::docs_str(&mod_attrs).trim().is_empty())
@@ -149,8 +173,11 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
             let functions_map = to_abi::functions_map(&functions, cx);
             let events_map = to_abi::events_map(&events, cx);
             let errors_map = to_abi::errors_map(&errors, cx);
+            let doc = docs.then(|| attr::mk_doc(
+                "Contains [dynamic ABI definitions](::alloy_sol_types::private::alloy_json_abi) for [this contract](self)."
+            ));
             quote! {
-                /// Contains [dynamic ABI definitions](::alloy_sol_types::private::alloy_json_abi) for [this contract](self).
+                #doc
                 pub mod abi {
                     use ::alloy_sol_types::private::{alloy_json_abi as json, BTreeMap, Vec};
 
@@ -210,6 +237,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
 
             #bytecode
             #deployed_bytecode
+            #deploy_builder
 
             #item_tokens
 
@@ -226,6 +254,83 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
 // note that item impls generated here do not need to be wrapped in an anonymous
 // constant (`const _: () = { ... };`) because they are in one already
 
+/// Expands the `deploy_builder` function, which returns the creation code of the
+/// contract followed by the ABI-encoded constructor arguments.
+fn expand_deploy_builder(constructor: Option<&ItemFunction>, docs: bool) -> TokenStream {
+    let (arg_names, arg_types): (Vec<_>, Vec<_>) = constructor
+        .map(|c| {
+            c.parameters
+                .names()
+                .enumerate()
+                .map(anon_name)
+                .zip(c.parameters.types().map(ty::expand_rust_type))
+                .unzip()
+        })
+        .unwrap_or_default();
+    let encoded_args = constructor.map(|_| {
+        quote! {
+            &<constructorCall as ::alloy_sol_types::SolConstructor>::abi_encode(
+                &constructorCall { #(#arg_names),* }
+            ),
+        }
+    });
+    let doc = docs.then(|| {
+        attr::mk_doc(
+            "Returns the [creation code](BYTECODE) of the contract followed by the \
+             ABI-encoded constructor arguments, ready to be sent in a deployment transaction.",
+        )
+    });
+    quote! {
+        #doc
+        #[inline]
+        #[allow(clippy::too_many_arguments)]
+        pub fn deploy_builder(#(#arg_names: #arg_types),*) -> ::alloy_sol_types::private::Vec<u8> {
+            [&BYTECODE[..], #encoded_args].concat()
+        }
+    }
+}
+
+fn expand_decode_call(contract_name: &SolIdent, docs: bool) -> TokenStream {
+    let calls = format_ident!("{contract_name}Calls");
+    let doc = docs.then(|| {
+        attr::mk_doc(format!(
+            "Decodes calldata, including the selector, into any of the [`{contract_name}`](self) \
+             function calls.\n\nSee [`SolInterface::abi_decode`](::alloy_sol_types::SolInterface::abi_decode)."
+        ))
+    });
+    quote! {
+        #doc
+        #[inline]
+        pub fn decode_call(data: &[u8], validate: bool) -> ::alloy_sol_types::Result<#calls> {
+            <#calls as ::alloy_sol_types::SolInterface>::abi_decode(data, validate)
+        }
+    }
+}
+
+fn expand_decode_event(contract_name: &SolIdent, docs: bool) -> TokenStream {
+    let events = format_ident!("{contract_name}Events");
+    let doc = docs.then(|| {
+        attr::mk_doc(format!(
+            "Decodes a log into any of the [`{contract_name}`](self) events.\n\n\
+             See [`SolEventInterface::decode_raw_log`](::alloy_sol_types::SolEventInterface::decode_raw_log)."
+        ))
+    });
+    quote! {
+        #doc
+        #[inline]
+        pub fn decode_event(
+            log: &::alloy_sol_types::private::Log,
+            validate: bool,
+        ) -> ::alloy_sol_types::Result<#events> {
+            <#events as ::alloy_sol_types::SolEventInterface>::decode_raw_log(
+                log.topics(),
+                &log.data.data,
+                validate,
+            )
+        }
+    }
+}
+
 /// Expands a `SolInterface` enum:
 ///
 /// ```ignore (pseudo-code)
@@ -260,6 +365,7 @@ struct CallLikeExpander<'a> {
     cx: &'a ExpCtxt<'a>,
     contract_name: SolIdent,
     extra_methods: bool,
+    debug: Option<bool>,
 }
 
 struct ExpandData {
@@ -505,12 +611,27 @@ impl<'a> CallLikeExpander<'a> {
         assert!(selectors.iter().all(|s| s.array.len() == selector_len));
         let selector_type = quote!([u8; #selector_len]);
         self.cx.type_derives(&mut attrs, types.iter().cloned().map(ast::Type::custom), false);
+        let debug = self.cx.debug(&mut attrs, self.debug).then(|| {
+            let names = variants.iter().map(|v| v.unraw().to_string());
+            quote! {
+                #[automatically_derived]
+                impl ::core::fmt::Debug for #name {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            #(Self::#variants(inner) => f.debug_tuple(#names).field(inner).finish(),)*
+                        }
+                    }
+                }
+            }
+        });
         let mut tokens = quote! {
             #(#attrs)*
             pub enum #name {
                 #(#variants(#types),)*
             }
 
+            #debug
+
             #[automatically_derived]
             impl #name {
                 /// All the selectors of this enum.
@@ -24,6 +24,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
 
     let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, [], false);
+    let debug = cx.debug(&mut attrs, sol_attrs.debug);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
 
     let name_s = name.to_string();
@@ -49,11 +50,16 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
         });
         let serde_other = has_serde.then(|| quote!(#[serde(other)]));
 
+        let doc = docs.then(|| {
+            quote! {
+                /// Invalid variant.
+                ///
+                /// This is only used when decoding an out-of-range `u8` value.
+            }
+        });
         quote! {
             #comma
-            /// Invalid variant.
-            ///
-            /// This is only used when decoding an out-of-range `u8` value.
+            #doc
             #[doc(hidden)]
             #serde_other
             __Invalid = u8::MAX,
@@ -65,6 +71,24 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
         quote! { expect("unreachable") }
     };
 
+    let debug = debug.then(|| {
+        let idents = variants.iter().map(|v| &v.ident);
+        let names = variants.iter().map(|v| v.ident.as_string());
+        let invalid =
+            has_invalid_variant.then(|| quote!(Self::__Invalid => f.write_str("__Invalid"),));
+        quote! {
+            #[automatically_derived]
+            impl ::core::fmt::Debug for #name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        #(Self::#idents => f.write_str(#names),)*
+                        #invalid
+                    }
+                }
+            }
+        }
+    });
+
     let uint8 = quote!(::alloy_sol_types::sol_data::Uint<8>);
     let uint8_st = quote!(<#uint8 as ::alloy_sol_types::SolType>);
 
@@ -82,6 +106,16 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
+            #debug
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::private::SolTypeDebug for #name {
+                #[inline]
+                fn fmt_debug(rust: &Self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Debug::fmt(rust, f)
+                }
+            }
+
             #[automatically_derived]
             impl ::core::convert::From<#name> for u8 {
                 #[inline]
@@ -1,6 +1,9 @@
 //! [`ItemError`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, ExpCtxt};
+use super::{
+    assert_no_max_bytes, expand_fields, expand_from_into_tuples, expand_params_debug,
+    expand_tokenize, ExpCtxt,
+};
 use crate::attr;
 use ast::ItemError;
 use proc_macro2::TokenStream;
@@ -21,9 +24,11 @@ use syn::Result;
 pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream> {
     let ItemError { parameters: params, name, attrs, .. } = error;
     cx.assert_resolved(params)?;
+    assert_no_max_bytes(params.iter().flat_map(|p| &p.attrs))?;
 
     let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, params, true);
+    let debug = cx.debug(&mut attrs, sol_attrs.debug);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
 
@@ -33,7 +38,9 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
     let selector = crate::utils::selector(&signature);
 
     let converts = expand_from_into_tuples(&name.0, params);
+    let debug = debug.then(|| expand_params_debug(&name.0, params));
     let fields = expand_fields(params);
+    let name_s = name.as_string();
     let doc = docs.then(|| {
         let selector = hex::encode_prefixed(selector.array.as_slice());
         attr::mk_doc(format!(
@@ -70,6 +77,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
         const _: () = {
             #converts
 
+            #debug
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolError for #name {
                 type Parameters<'a> = UnderlyingSolTuple<'a>;
@@ -87,6 +96,13 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
                 fn tokenize(&self) -> Self::Token<'_> {
                     #tokenize_impl
                 }
+
+                #[inline]
+                fn abi_decode_raw(data: &[u8], validate: bool) -> ::alloy_sol_types::Result<Self> {
+                    <Self::Parameters<'_> as ::alloy_sol_types::SolType>::abi_decode_sequence(data, validate)
+                        .map(Self::new)
+                        .map_err(|e| ::alloy_sol_types::Error::decode_failed(#name_s, e))
+                }
             }
 
             #abi
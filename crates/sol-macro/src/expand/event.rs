@@ -1,6 +1,9 @@
 //! [`ItemEvent`] expansion.
 
-use super::{anon_name, expand_event_tokenize, expand_tuple_types, expand_type, ty, ExpCtxt};
+use super::{
+    anon_name, assert_no_max_bytes, expand_debug, expand_event_tokenize, expand_tuple_types,
+    expand_type, ty, ExpCtxt,
+};
 use crate::attr;
 use ast::{EventParameter, ItemEvent, SolIdent, Spanned};
 use proc_macro2::TokenStream;
@@ -24,20 +27,23 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
 
     let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, &params, true);
+    let debug = cx.debug(&mut attrs, sol_attrs.debug);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
 
     cx.assert_resolved(&params)?;
     event.assert_valid()?;
+    assert_no_max_bytes(event.parameters.iter().flat_map(|p| &p.attrs))?;
 
     let name = cx.overloaded_name(event.into());
-    let signature = cx.signature(name.as_string(), &params);
+    let name_s = name.as_string();
+    let signature = cx.signature(name_s.clone(), &params);
     let selector = crate::utils::event_selector(&signature);
     let anonymous = event.is_anonymous();
 
     // prepend the first topic if not anonymous
     let first_topic = (!anonymous).then(|| quote!(::alloy_sol_types::sol_data::FixedBytes<32>));
-    let topic_list = event.indexed_params().map(expand_event_topic_type);
+    let topic_list = event.indexed_params().map(|p| expand_event_topic_type(cx, p));
     let topic_list = first_topic.into_iter().chain(topic_list);
 
     let (data_tuple, _) = expand_tuple_types(event.non_indexed_params().map(|p| &p.ty));
@@ -76,7 +82,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         let name = anon_name((i, p.name.as_ref()));
         let ty = expand_type(&p.ty);
 
-        if p.indexed_as_hash() {
+        if cx.indexed_as_hash(p) {
             quote! {
                 <::alloy_sol_types::sol_data::FixedBytes<32> as ::alloy_sol_types::EventTopic>::encode_topic(&self.#name)
             }
@@ -91,7 +97,19 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         .parameters
         .iter()
         .enumerate()
-        .map(|(i, p)| expand_event_topic_field(i, p, p.name.as_ref()));
+        .map(|(i, p)| expand_event_topic_field(cx, i, p, p.name.as_ref()));
+
+    let debug_fields = event.parameters.iter().enumerate().map(|(i, p)| {
+        let name = anon_name((i, p.name.as_ref()));
+        let ty = if cx.indexed_as_hash(p) {
+            quote!(::alloy_sol_types::sol_data::FixedBytes<32>)
+        } else {
+            expand_type(&p.ty)
+        };
+        let value = quote!(&self.#name);
+        (name, ty, value)
+    });
+    let debug = debug.then(|| expand_debug(&name.0, debug_fields));
 
     let tokenize_body_impl = expand_event_tokenize(&event.parameters);
 
@@ -136,6 +154,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
+            #debug
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolEvent for #name {
                 type DataTuple<'a> = #data_tuple;
@@ -181,6 +201,11 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                     #(#encode_topics_impl)*
                     Ok(())
                 }
+
+                #[inline]
+                fn map_decode_err(error: ::alloy_sol_types::Error) -> ::alloy_sol_types::Error {
+                    ::alloy_sol_types::Error::decode_failed(#name_s, error)
+                }
             }
 
             #abi
@@ -189,9 +214,9 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     Ok(tokens)
 }
 
-fn expand_event_topic_type(param: &EventParameter) -> TokenStream {
+fn expand_event_topic_type(cx: &ExpCtxt<'_>, param: &EventParameter) -> TokenStream {
     assert!(param.is_indexed());
-    if param.is_abi_dynamic() {
+    if cx.indexed_as_hash(param) {
         quote_spanned! {param.ty.span()=> ::alloy_sol_types::sol_data::FixedBytes<32> }
     } else {
         expand_type(&param.ty)
@@ -199,12 +224,13 @@ fn expand_event_topic_type(param: &EventParameter) -> TokenStream {
 }
 
 fn expand_event_topic_field(
+    cx: &ExpCtxt<'_>,
     i: usize,
     param: &EventParameter,
     name: Option<&SolIdent>,
 ) -> TokenStream {
     let name = anon_name((i, name));
-    let ty = if param.indexed_as_hash() {
+    let ty = if cx.indexed_as_hash(param) {
         ty::expand_rust_type(&ast::Type::FixedBytes(
             name.span(),
             core::num::NonZeroU16::new(32).unwrap(),
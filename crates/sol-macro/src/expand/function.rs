@@ -1,11 +1,15 @@
 //! [`ItemFunction`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, expand_tuple_types, ExpCtxt};
+use super::{
+    anon_name, assert_no_max_bytes, expand_fields, expand_from_into_tuples,
+    expand_max_bytes_checks, expand_params_debug, expand_tokenize, expand_tuple_types, ty, ExpCtxt,
+};
 use crate::attr;
-use ast::{FunctionKind, ItemFunction};
-use proc_macro2::TokenStream;
+use ast::{FunctionKind, ItemFunction, Mutability, Parameters};
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use syn::Result;
+use std::fmt::Write;
+use syn::{ext::IdentExt, Result, Token};
 
 /// Expands an [`ItemFunction`]:
 ///
@@ -41,6 +45,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     if !returns.is_empty() {
         cx.assert_resolved(returns)?;
     }
+    assert_no_max_bytes(returns.iter().flat_map(|p| &p.attrs))?;
 
     let (sol_attrs, mut call_attrs) = crate::attr::SolAttrs::parse(attrs)?;
     let mut return_attrs = call_attrs.clone();
@@ -48,6 +53,11 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     if !returns.is_empty() {
         cx.derives(&mut return_attrs, returns, true);
     }
+    let copy = sol_attrs.copy.unwrap_or(false);
+    cx.derive_copy(&mut call_attrs, parameters, copy)?;
+    cx.derive_copy(&mut return_attrs, returns, copy)?;
+    let debug = cx.debug(&mut call_attrs, sol_attrs.debug);
+    cx.debug(&mut return_attrs, sol_attrs.debug);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
 
@@ -63,9 +73,50 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     let converts = expand_from_into_tuples(&call_name, parameters);
     let return_converts = expand_from_into_tuples(&return_name, returns);
 
+    let arg_names = parameters.names().enumerate().map(anon_name);
+    let arg_names2 = arg_names.clone();
+    let arg_types = parameters.types().map(ty::expand_rust_type);
+
+    let call_debug = debug.then(|| expand_params_debug(&call_name, parameters));
+    let return_debug = debug.then(|| expand_params_debug(&return_name, returns));
+
     let signature = cx.function_signature(function);
     let selector = crate::utils::selector(&signature);
+    let payable = matches!(function.attributes.mutability(), Some(Mutability::Payable(_)));
     let tokenize_impl = expand_tokenize(parameters);
+    let return_tokenize_impl = expand_tokenize(returns);
+    let decode_raw_impl = expand_decode_raw(&call_name, parameters)?;
+    let return_name_s = return_name.to_string();
+    let return_names = returns.names().enumerate().map(|x| anon_name(x).unraw().to_string());
+
+    // a function without return values should not return any data: this is most likely revert
+    // data, so it is surfaced as such instead of being silently ignored
+    let decode_returns_impl = if returns.is_empty() {
+        quote! {
+            if data.is_empty() {
+                ::core::result::Result::Ok(#return_name {})
+            } else {
+                ::core::result::Result::Err(::alloy_sol_types::Error::UnexpectedReturnData(
+                    ::alloy_sol_types::private::Bytes::copy_from_slice(data)
+                ))
+            }
+        }
+    } else if returns.len() == 1 && cx.make_resolved_type(&returns[0].ty).is_one_word() {
+        // when validating, a single word is checked by its length instead of by re-encoding it,
+        // which would allocate
+        let ty = ty::expand_type(&returns[0].ty);
+        quote! {
+            ::alloy_sol_types::private::decode_word::<#ty>(data, validate)
+                .map(|value| (value,).into())
+                .map_err(|e| ::alloy_sol_types::Error::decode_failed(#return_name_s, e))
+        }
+    } else {
+        quote! {
+            <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::abi_decode_sequence(data, validate)
+                .map(Into::into)
+                .map_err(|e| ::alloy_sol_types::Error::decode_failed(#return_name_s, e))
+        }
+    };
 
     let call_doc = docs.then(|| {
         let selector = hex::encode_prefixed(selector.array.as_slice());
@@ -75,9 +126,13 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
         ))
     });
     let return_doc = docs.then(|| {
-        attr::mk_doc(format!(
+        let mut doc = format!(
             "Container type for the return parameters of the [`{signature}`]({call_name}) function."
-        ))
+        );
+        if let Some(returns) = &function.returns {
+            write!(doc, "\n```solidity\n{returns}\n```").unwrap();
+        }
+        attr::mk_doc(doc)
     });
 
     let abi: Option<TokenStream> = abi.then(|| {
@@ -97,6 +152,34 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
         }
     });
 
+    let call_method = cfg!(feature = "async").then(|| {
+        // reverts are decoded with the errors of the enclosing contract, if any
+        let errors = match cx.contract_errors() {
+            Some(errors) => quote!(#errors),
+            None => quote!(::core::convert::Infallible),
+        };
+        quote! {
+            #[automatically_derived]
+            impl #call_name {
+                /// ABI-encodes this call, executes it against `to` using the given
+                /// provider, and decodes the returned data.
+                ///
+                /// If the call reverts, the revert data is decoded as a
+                /// [`ContractError`](::alloy_sol_types::ContractError) instead.
+                #[inline]
+                pub async fn call<P: ::alloy_sol_types::CallProvider + ?Sized>(
+                    &self,
+                    provider: &P,
+                    to: ::alloy_sol_types::private::Address,
+                ) -> ::alloy_sol_types::Result<
+                    ::core::result::Result<#return_name, ::alloy_sol_types::ContractError<#errors>>
+                > {
+                    ::alloy_sol_types::private::call::<Self, #errors, P>(self, provider, to).await
+                }
+            }
+        }
+    });
+
     let tokens = quote! {
         #(#call_attrs)*
         #call_doc
@@ -119,6 +202,31 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
             { #converts }
             { #return_converts }
 
+            #call_debug
+            #return_debug
+
+            #[automatically_derived]
+            impl #call_name {
+                /// Creates a new call from its arguments.
+                #[inline]
+                #[allow(clippy::too_many_arguments)]
+                pub fn new_args(#(#arg_names: #arg_types),*) -> Self {
+                    Self { #(#arg_names2),* }
+                }
+            }
+
+            #[automatically_derived]
+            impl #return_name {
+                /// ABI-encodes the return values, as returned by the function.
+                ///
+                /// This can be decoded with [`SolCall::abi_decode_returns`](::alloy_sol_types::SolCall::abi_decode_returns).
+                #[inline]
+                pub fn abi_encode(&self) -> ::alloy_sol_types::private::Vec<u8> {
+                    let tokens: <#return_tuple as ::alloy_sol_types::SolType>::Token<'_> = #return_tokenize_impl;
+                    ::alloy_sol_types::abi::encode_sequence(&tokens)
+                }
+            }
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolCall for #call_name {
                 type Parameters<'a> = #call_tuple;
@@ -131,6 +239,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
 
                 const SIGNATURE: &'static str = #signature;
                 const SELECTOR: [u8; 4] = #selector;
+                const PAYABLE: bool = #payable;
+                const RETURN_NAMES: &'static [&'static str] = &[#(#return_names),*];
 
                 fn new<'a>(tuple: <Self::Parameters<'a> as ::alloy_sol_types::SolType>::RustType) -> Self {
                     tuple.into()
@@ -140,26 +250,79 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
                     #tokenize_impl
                 }
 
+                #decode_raw_impl
+
+                #[allow(unused_variables)]
                 fn abi_decode_returns(data: &[u8], validate: bool) -> ::alloy_sol_types::Result<Self::Return> {
-                    <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::abi_decode_sequence(data, validate).map(Into::into)
+                    #decode_returns_impl
                 }
             }
 
+            #call_method
+
             #abi
         };
     };
     Ok(tokens)
 }
 
+/// Expands `abi_decode_raw`, which names the call in decoding errors and
+/// checks the lengths of the parameters annotated with
+/// `#[sol(max_bytes = <N>)]`, if any.
+///
+/// The lengths are checked on the decoded tokens, which borrow from the input,
+/// before the values are copied out of it.
+fn expand_decode_raw(call_name: &Ident, parameters: &Parameters<Token![,]>) -> Result<TokenStream> {
+    let call_name_s = call_name.to_string();
+    let checks = expand_max_bytes_checks(
+        parameters,
+        |e| quote!(::alloy_sol_types::Error::decode_failed(#call_name_s, #e)),
+    )?;
+
+    if checks.is_empty() {
+        return Ok(quote! {
+            #[inline]
+            fn abi_decode_raw(data: &[u8], validate: bool) -> ::alloy_sol_types::Result<Self> {
+                <Self::Parameters<'_> as ::alloy_sol_types::SolType>::abi_decode_sequence(data, validate)
+                    .map(Self::new)
+                    .map_err(|e| ::alloy_sol_types::Error::decode_failed(#call_name_s, e))
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[inline]
+        fn abi_decode_raw(data: &[u8], validate: bool) -> ::alloy_sol_types::Result<Self> {
+            let token = ::alloy_sol_types::abi::Decoder::new(data, validate)
+                .decode_sequence::<Self::Token<'_>>()
+                .map_err(|e| ::alloy_sol_types::Error::decode_failed(#call_name_s, e))?;
+            #(#checks)*
+            if validate {
+                if ::alloy_sol_types::abi::encode_sequence(&token) != data {
+                    let e = ::alloy_sol_types::Error::ReserMismatch;
+                    return Err(::alloy_sol_types::Error::decode_failed(#call_name_s, e));
+                }
+                <Self::Parameters<'_> as ::alloy_sol_types::SolType>::type_check(&token)
+                    .map_err(|e| ::alloy_sol_types::Error::decode_failed(#call_name_s, e))?;
+            }
+            Ok(Self::new(<Self::Parameters<'_> as ::alloy_sol_types::SolType>::detokenize(token)))
+        }
+    })
+}
+
 fn expand_constructor(cx: &ExpCtxt<'_>, constructor: &ItemFunction) -> Result<TokenStream> {
     let ItemFunction { attrs, parameters, .. } = constructor;
 
-    let (sol_attrs, call_attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    assert_no_max_bytes(parameters.iter().flat_map(|p| &p.attrs))?;
+
+    let (sol_attrs, mut call_attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    let debug = cx.debug(&mut call_attrs, sol_attrs.debug);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let call_name = format_ident!("constructorCall");
     let call_fields = expand_fields(parameters);
     let call_tuple = expand_tuple_types(parameters.types()).0;
     let converts = expand_from_into_tuples(&call_name, parameters);
+    let debug = debug.then(|| expand_params_debug(&call_name, parameters));
     let tokenize_impl = expand_tokenize(parameters);
 
     let call_doc = docs.then(|| {
@@ -181,6 +344,8 @@ fn expand_constructor(cx: &ExpCtxt<'_>, constructor: &ItemFunction) -> Result<To
         const _: () = {
             { #converts }
 
+            #debug
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolConstructor for #call_name {
                 type Parameters<'a> = #call_tuple;
@@ -10,3 +10,16 @@ macro_rules! if_json {
         TokenStream::new()
     };
 }
+
+#[cfg(feature = "rlp")]
+macro_rules! if_rlp {
+    ($($t:tt)*) => { $($t)* };
+}
+
+#[cfg(not(feature = "rlp"))]
+macro_rules! if_rlp {
+    ($($t:tt)*) => {
+        crate::expand::emit_rlp_error();
+        TokenStream::new()
+    };
+}
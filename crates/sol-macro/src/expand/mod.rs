@@ -6,12 +6,12 @@ use crate::{
     utils::{self, ExprArray},
 };
 use ast::{
-    EventParameter, File, Item, ItemError, ItemEvent, ItemFunction, Parameters, SolIdent, SolPath,
-    Spanned, Type, VariableDeclaration, Visit,
+    EventParameter, File, Item, ItemContract, ItemError, ItemEvent, ItemFunction, Parameters,
+    SolIdent, SolPath, Spanned, Type, VariableDeclaration, Visit,
 };
 use indexmap::IndexMap;
-use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
-use quote::{format_ident, quote, TokenStreamExt};
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, TokenStreamExt};
 use std::{
     borrow::Borrow,
     fmt::Write,
@@ -50,10 +50,13 @@ pub struct ExpCtxt<'ast> {
     all_items: Vec<&'ast Item>,
     custom_types: IndexMap<SolIdent, Type>,
 
-    /// `name => item`
-    overloaded_items: IndexMap<String, Vec<OverloadedItem<'ast>>>,
-    /// `signature => new_name`
-    overloads: IndexMap<String, String>,
+    /// `namespace => name => item`
+    overloaded_items: IndexMap<Option<SolIdent>, IndexMap<String, Vec<OverloadedItem<'ast>>>>,
+    /// `namespace => signature => new_name`
+    overloads: IndexMap<Option<SolIdent>, IndexMap<String, String>>,
+    /// The name of the contract whose items are being visited or expanded,
+    /// if any. Overloads are resolved separately for each contract.
+    current_namespace: Option<SolIdent>,
 
     attrs: SolAttrs,
     ast: &'ast File,
@@ -67,6 +70,7 @@ impl<'ast> ExpCtxt<'ast> {
             custom_types: IndexMap::new(),
             overloaded_items: IndexMap::new(),
             overloads: IndexMap::new(),
+            current_namespace: None,
             attrs: SolAttrs::default(),
             ast,
         }
@@ -94,6 +98,10 @@ impl<'ast> ExpCtxt<'ast> {
         }
 
         for item in &self.ast.items {
+            self.current_namespace = match item {
+                Item::Contract(contract) => Some(contract.name.clone()),
+                _ => None,
+            };
             // TODO: Dummy items
             let t = match self.expand_item(item) {
                 Ok(t) => t,
@@ -101,6 +109,7 @@ impl<'ast> ExpCtxt<'ast> {
             };
             tokens.extend(t);
         }
+        self.current_namespace = None;
         Ok(tokens)
     }
 
@@ -175,47 +184,50 @@ impl<'ast> ExpCtxt<'ast> {
     }
 
     fn mk_overloads_map(&mut self) -> std::result::Result<(), ()> {
-        let all_orig_names: Vec<_> =
-            self.overloaded_items.values().flatten().filter_map(|f| f.name()).collect();
         let mut overloads_map = std::mem::take(&mut self.overloads);
 
         let mut failed = false;
 
-        for functions in self.overloaded_items.values().filter(|fs| fs.len() >= 2) {
-            // check for same parameters
-            for (i, &a) in functions.iter().enumerate() {
-                for &b in functions.iter().skip(i + 1) {
-                    if a.eq_by_types(b) {
+        for (namespace, items) in &self.overloaded_items {
+            let all_orig_names: Vec<_> =
+                items.values().flatten().filter_map(|f| f.name()).collect();
+            let namespace_overloads = overloads_map.entry(namespace.clone()).or_default();
+            for functions in items.values().filter(|fs| fs.len() >= 2) {
+                // check for same parameters
+                for (i, &a) in functions.iter().enumerate() {
+                    for &b in functions.iter().skip(i + 1) {
+                        if a.eq_by_types(b) {
+                            failed = true;
+                            emit_error!(
+                                a.span(),
+                                "{} with same name and parameter types defined twice",
+                                a.desc();
+
+                                note = b.span() => "other declaration is here";
+                            );
+                        }
+                    }
+                }
+
+                for (i, &item) in functions.iter().enumerate() {
+                    let Some(old_name) = item.name() else {
+                        continue;
+                    };
+                    let new_name = format!("{old_name}_{i}");
+                    if let Some(other) = all_orig_names.iter().find(|x| x.0 == new_name) {
                         failed = true;
                         emit_error!(
-                            a.span(),
-                            "{} with same name and parameter types defined twice",
-                            a.desc();
+                            old_name.span(),
+                            "{} `{old_name}` is overloaded, \
+                             but the generated name `{new_name}` is already in use",
+                            item.desc();
 
-                            note = b.span() => "other declaration is here";
-                        );
+                            note = other.span() => "other declaration is here";
+                        )
                     }
-                }
-            }
 
-            for (i, &item) in functions.iter().enumerate() {
-                let Some(old_name) = item.name() else {
-                    continue;
-                };
-                let new_name = format!("{old_name}_{i}");
-                if let Some(other) = all_orig_names.iter().find(|x| x.0 == new_name) {
-                    failed = true;
-                    emit_error!(
-                        old_name.span(),
-                        "{} `{old_name}` is overloaded, \
-                         but the generated name `{new_name}` is already in use",
-                        item.desc();
-
-                        note = other.span() => "other declaration is here";
-                    )
+                    namespace_overloads.insert(item.signature(self), new_name);
                 }
-
-                overloads_map.insert(item.signature(self), new_name);
             }
         }
 
@@ -234,9 +246,17 @@ impl<'ast> Visit<'ast> for ExpCtxt<'ast> {
         ast::visit::visit_item(self, item);
     }
 
+    fn visit_item_contract(&mut self, contract: &'ast ItemContract) {
+        let prev = self.current_namespace.replace(contract.name.clone());
+        ast::visit::visit_item_contract(self, contract);
+        self.current_namespace = prev;
+    }
+
     fn visit_item_function(&mut self, function: &'ast ItemFunction) {
         if let Some(name) = &function.name {
             self.overloaded_items
+                .entry(self.current_namespace.clone())
+                .or_default()
                 .entry(name.as_string())
                 .or_default()
                 .push(OverloadedItem::Function(function));
@@ -246,6 +266,8 @@ impl<'ast> Visit<'ast> for ExpCtxt<'ast> {
 
     fn visit_item_event(&mut self, event: &'ast ItemEvent) {
         self.overloaded_items
+            .entry(self.current_namespace.clone())
+            .or_default()
             .entry(event.name.as_string())
             .or_default()
             .push(OverloadedItem::Event(event));
@@ -319,6 +341,21 @@ impl<'ast> ExpCtxt<'ast> {
         }
     }
 
+    /// Returns the name of the errors container enum of the contract whose
+    /// items are being expanded, if it has any errors.
+    fn contract_errors(&self) -> Option<Ident> {
+        let name = self.current_namespace.as_ref()?;
+        self.ast.items.iter().find_map(|item| match item {
+            Item::Contract(contract)
+                if contract.name == *name
+                    && contract.body.iter().any(|item| matches!(item, Item::Error(_))) =>
+            {
+                Some(format_ident!("{name}Errors"))
+            }
+            _ => None,
+        })
+    }
+
     fn try_item(&self, name: &SolPath) -> Option<&Item> {
         let name = name.last();
         self.all_items.iter().copied().find(|item| item.name() == Some(name))
@@ -336,6 +373,19 @@ impl<'ast> ExpCtxt<'ast> {
         ty
     }
 
+    /// Returns `true` if the given event parameter is indexed and its value is
+    /// hashed in the topics, i.e. if its resolved type is not a value type.
+    ///
+    /// Unlike [`EventParameter::indexed_as_hash`], this also applies to static
+    /// arrays, tuples and structs, and resolves custom types.
+    ///
+    /// See [the Solidity spec][ref] for more details.
+    ///
+    /// [ref]: https://docs.soliditylang.org/en/latest/abi-spec.html#events
+    fn indexed_as_hash(&self, param: &EventParameter) -> bool {
+        param.is_indexed() && !self.make_resolved_type(&param.ty).is_one_word()
+    }
+
     fn custom_type(&self, name: &SolPath) -> &Type {
         match self.try_custom_type(name) {
             Some(item) => item,
@@ -358,7 +408,7 @@ impl<'ast> ExpCtxt<'ast> {
     fn overloaded_name(&self, item: OverloadedItem<'ast>) -> SolIdent {
         let original_ident = item.name().expect("item has no name");
         let sig = item.signature(self);
-        match self.overloads.get(&sig) {
+        match self.overloads.get(&self.current_namespace).and_then(|o| o.get(&sig)) {
             Some(name) => SolIdent::new_spanned(name, original_ident.span()),
             None => original_ident.clone(),
         }
@@ -481,12 +531,46 @@ impl<'ast> ExpCtxt<'ast> {
             derives.push("Default");
         }
         if derive_others {
-            derives.extend(["Debug", "PartialEq", "Eq", "Hash"]);
+            derives.extend(["PartialEq", "Eq", "Hash"]);
         }
         let derives = derives.iter().map(|s| Ident::new(s, Span::call_site()));
         attrs.push(parse_quote! { #[derive(#(#derives),*)] });
     }
 
+    /// Returns whether to expand the human-readable `Debug` implementation,
+    /// removing `Debug` from the derives in `attrs` if so.
+    fn debug(&self, attrs: &mut Vec<Attribute>, debug: Option<bool>) -> bool {
+        let debug = debug.or(self.attrs.debug).unwrap_or(true);
+        if debug {
+            attr::strip_derive_debug(attrs);
+        }
+        debug
+    }
+
+    /// Derives `Copy` if `copy` is set, returning an error naming the first
+    /// parameter whose type is not `Copy`.
+    fn derive_copy<'a, I>(&self, attrs: &mut Vec<Attribute>, params: I, copy: bool) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a VariableDeclaration>,
+    {
+        if !copy {
+            return Ok(());
+        }
+
+        for (i, param) in params.into_iter().enumerate() {
+            if !ty::can_derive_copy(self, &param.ty) {
+                let name = anon_name((i, param.name.as_ref()));
+                let msg = format!(
+                    "cannot derive `Copy`: field `{name}` of type `{}` is not `Copy`",
+                    param.ty
+                );
+                return Err(Error::new(param.span(), msg));
+            }
+        }
+        attrs.push(parse_quote! { #[derive(Copy)] });
+        Ok(())
+    }
+
     /// Returns an error if any of the types in the parameters are unresolved.
     ///
     /// Provides a better error message than an `unwrap` or `expect` when we
@@ -518,10 +602,27 @@ impl<'ast> ExpCtxt<'ast> {
 
 /// Expands a list of parameters into a list of struct fields.
 fn expand_fields<P>(params: &Parameters<P>) -> impl Iterator<Item = TokenStream> + '_ {
-    params.iter().enumerate().map(|(i, var)| {
+    expand_fields_with(params, false)
+}
+
+/// Expands the fields of a struct-like item.
+///
+/// If `fixed_bytes_as_array` is `true`, `bytesN` fields are expanded as
+/// `[u8; N]` instead of `FixedBytes<N>`.
+fn expand_fields_with<P>(
+    params: &Parameters<P>,
+    fixed_bytes_as_array: bool,
+) -> impl Iterator<Item = TokenStream> + '_ {
+    params.iter().enumerate().map(move |(i, var)| {
         let name = anon_name((i, var.name.as_ref()));
-        let ty = expand_rust_type(&var.ty);
-        let attrs = &var.attrs;
+        let ty = match var.ty {
+            Type::FixedBytes(span, size) if fixed_bytes_as_array => {
+                let size = Literal::u16_unsuffixed(size.get());
+                quote_spanned!(span=> [u8; #size])
+            }
+            ref ty => expand_rust_type(ty),
+        };
+        let attrs = var.attrs.iter().filter(|attr| !attr.path().is_ident("sol"));
         quote! {
             #(#attrs)*
             pub #name: #ty
@@ -529,6 +630,54 @@ fn expand_fields<P>(params: &Parameters<P>) -> impl Iterator<Item = TokenStream>
     })
 }
 
+/// Returns an error if any of the given attributes contains
+/// `#[sol(max_bytes = <N>)]`, which is only supported on function parameters
+/// and struct fields.
+fn assert_no_max_bytes<'a>(attrs: impl IntoIterator<Item = &'a Attribute>) -> Result<()> {
+    for attr in attrs.into_iter().filter(|attr| attr.path().is_ident("sol")) {
+        if SolAttrs::parse(std::slice::from_ref(attr))?.0.max_bytes.is_some() {
+            return Err(Error::new(
+                syn::spanned::Spanned::span(attr),
+                "`max_bytes` is only supported on function parameters and struct fields",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Expands the length checks of the parameters annotated with
+/// `#[sol(max_bytes = <N>)]`, which must be `bytes` or `string`.
+///
+/// Each check reads the length of a `token` tuple element, and returns
+/// `Error::LimitExceeded` wrapped with `map_err` if it exceeds its limit.
+fn expand_max_bytes_checks<P>(
+    params: &Parameters<P>,
+    map_err: impl Fn(TokenStream) -> TokenStream,
+) -> Result<Vec<TokenStream>> {
+    let mut checks = Vec::new();
+    for (i, param) in params.iter().enumerate() {
+        let Some(limit) = SolAttrs::parse(&param.attrs)?.0.max_bytes else { continue };
+        if !matches!(param.ty, Type::Bytes(_) | Type::String(_)) {
+            return Err(Error::new(
+                param.ty.span(),
+                "`max_bytes` is only supported on `bytes` and `string` values",
+            ));
+        }
+
+        let name_s = anon_name((i, param.name.as_ref())).unraw().to_string();
+        let i = syn::Index::from(i);
+        let e = map_err(quote!(e));
+        checks.push(quote! {
+            let len = token.#i.0.len();
+            if len > #limit {
+                let e = ::alloy_sol_types::Error::LimitExceeded { name: #name_s, limit: #limit, len };
+                return Err(#e);
+            }
+        });
+    }
+    Ok(checks)
+}
+
 /// Generates an anonymous name from an integer. Used in [`anon_name`].
 #[inline]
 pub fn generate_name(i: usize) -> Ident {
@@ -543,9 +692,68 @@ fn anon_name<T: Into<Ident> + Clone>((i, name): (usize, Option<&T>)) -> Ident {
     }
 }
 
+/// Expands a human-readable `Debug` implementation for a struct with the given
+/// fields, their Solidity types, and a reference to their values as the Rust
+/// type of the Solidity type.
+///
+/// See `SolTypeDebug` for more details on the formatting.
+fn expand_debug<I>(name: &Ident, fields: I) -> TokenStream
+where
+    I: IntoIterator<Item = (Ident, TokenStream, TokenStream)>,
+{
+    let name_s = name.unraw().to_string();
+    let (names_s, fields): (Vec<_>, Vec<_>) = fields
+        .into_iter()
+        .map(|(name, ty, value)| {
+            let field = quote!(::alloy_sol_types::private::DebugWith::<#ty>(#value));
+            (name.unraw().to_string(), field)
+        })
+        .unzip();
+    quote! {
+        #[automatically_derived]
+        impl ::core::fmt::Debug for #name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(#name_s)
+                    #(.field(#names_s, &#fields))*
+                    .finish()
+            }
+        }
+    }
+}
+
+/// Expands a `Debug` implementation for a list of parameters.
+///
+/// See [`expand_debug`].
+fn expand_params_debug<P>(name: &Ident, params: &Parameters<P>) -> TokenStream {
+    let fields = params.iter().enumerate().map(|(i, p)| {
+        let name = anon_name((i, p.name.as_ref()));
+        let value = quote!(&self.#name);
+        (name, expand_type(&p.ty), value)
+    });
+    expand_debug(name, fields)
+}
+
 /// Expands `From` impls for a list of types and the corresponding tuple.
 fn expand_from_into_tuples<P>(name: &Ident, fields: &Parameters<P>) -> TokenStream {
+    expand_from_into_tuples_with(name, fields, false)
+}
+
+/// Expands `From` impls for a list of types and the corresponding tuple.
+///
+/// If `fixed_bytes_as_array` is `true`, `bytesN` fields are converted from and
+/// into `FixedBytes<N>`, as they are stored as `[u8; N]`.
+fn expand_from_into_tuples_with<P>(
+    name: &Ident,
+    fields: &Parameters<P>,
+    fixed_bytes_as_array: bool,
+) -> TokenStream {
     let names = fields.names().enumerate().map(anon_name);
+    let convert = fields
+        .types()
+        .map(|ty| {
+            (fixed_bytes_as_array && matches!(ty, Type::FixedBytes(..))).then(|| quote!(.into()))
+        })
+        .collect::<Vec<_>>();
 
     let names2 = names.clone();
     let idxs = (0..fields.len()).map(syn::Index::from);
@@ -570,7 +778,7 @@ fn expand_from_into_tuples<P>(name: &Ident, fields: &Parameters<P>) -> TokenStre
         #[doc(hidden)]
         impl ::core::convert::From<#name> for UnderlyingRustTuple<'_> {
             fn from(value: #name) -> Self {
-                (#(value.#names,)*)
+                (#(value.#names #convert,)*)
             }
         }
 
@@ -579,7 +787,7 @@ fn expand_from_into_tuples<P>(name: &Ident, fields: &Parameters<P>) -> TokenStre
         impl ::core::convert::From<UnderlyingRustTuple<'_>> for #name {
             fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
                 Self {
-                    #(#names2: tuple.#idxs),*
+                    #(#names2: tuple.#idxs #convert),*
                 }
             }
         }
@@ -646,3 +854,81 @@ fn emit_json_error() {
         );
     }
 }
+
+#[allow(dead_code)]
+fn emit_rlp_error() {
+    static EMITTED: AtomicBool = AtomicBool::new(false);
+    if !EMITTED.swap(true, Ordering::Relaxed) {
+        emit_error!(
+            Span::call_site(),
+            "the `#[sol(rlp)]` attribute requires the `\"rlp\"` feature"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the generated item-level doc comments, skipping `impl` blocks.
+    fn item_docs(tokens: TokenStream, docs: &mut Vec<String>) {
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(tt) = tokens.next() {
+            match tt {
+                TokenTree::Ident(ident) if ident == "impl" => {
+                    for tt in tokens.by_ref() {
+                        if matches!(&tt, TokenTree::Group(g) if g.delimiter() == Delimiter::Brace) {
+                            break;
+                        }
+                    }
+                }
+                TokenTree::Punct(p) if p.as_char() == '#' => {
+                    if let Some(TokenTree::Group(g)) = tokens.peek() {
+                        let attr = g.stream().to_string();
+                        if attr.starts_with("doc =") {
+                            docs.push(attr);
+                        }
+                    }
+                }
+                TokenTree::Group(g) => item_docs(g.stream(), docs),
+                _ => {}
+            }
+        }
+    }
+
+    fn expand_docs(input: &str) -> Vec<String> {
+        let file: File = syn::parse_str(input).unwrap();
+        let mut docs = Vec::new();
+        item_docs(expand(file).unwrap(), &mut docs);
+        docs
+    }
+
+    #[test]
+    fn docs_false() {
+        let items = r#"
+            struct MyStruct { uint256 a; }
+            enum MyEnum { A, B }
+            type MyUdt is uint256;
+
+            #[sol(bytecode = "0x00", deployed_bytecode = "0x00")]
+            contract MyContract {
+                constructor(uint256 a);
+                error MyError(uint256 a);
+                event MyEvent(uint256 a);
+                function myFunction(uint256 a) returns (uint256 b);
+            }
+        "#;
+
+        assert!(!expand_docs(items).is_empty());
+        let docs = expand_docs(&format!("#![sol(docs = false)] {items}"));
+        assert!(docs.is_empty(), "{docs:#?}");
+    }
+
+    #[test]
+    fn named_returns_docs() {
+        let docs = expand_docs("function f(uint256 a) returns (uint256 amount, bool success);");
+        let returns = "returns (uint256 amount, bool success)";
+        let return_doc = docs.iter().find(|doc| doc.contains("return parameters")).unwrap();
+        assert!(return_doc.contains(returns), "{return_doc}");
+    }
+}
@@ -1,9 +1,12 @@
 //! [`ItemStruct`] expansion.
 
-use super::{attr, expand_fields, expand_from_into_tuples, expand_tokenize, expand_type, ExpCtxt};
+use super::{
+    attr, expand_debug, expand_fields_with, expand_from_into_tuples_with, expand_max_bytes_checks,
+    expand_tokenize, expand_type, ExpCtxt,
+};
 use ast::{Item, ItemStruct, Spanned, Type};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use std::num::NonZeroU16;
 use syn::Result;
 
@@ -26,13 +29,36 @@ use syn::Result;
 pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
     let ItemStruct { name, fields, attrs, .. } = s;
 
+    let max_bytes_checks = expand_max_bytes_checks(fields, |e| e)?;
+
     let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, fields, true);
+    cx.derive_copy(&mut attrs, fields, sol_attrs.copy.unwrap_or(false))?;
+    let debug = cx.debug(&mut attrs, sol_attrs.debug);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let rlp = sol_attrs.rlp.or(cx.attrs.rlp).unwrap_or(false);
+    let fixed_bytes_as_array =
+        sol_attrs.fixed_bytes_as_array.or(cx.attrs.fixed_bytes_as_array).unwrap_or(false);
 
     let (field_types, field_names): (Vec<_>, Vec<_>) =
         fields.iter().map(|f| (expand_type(&f.ty), f.name.as_ref().unwrap())).unzip();
 
+    // `bytesN` fields stored as `[u8; N]` must be wrapped where a reference to
+    // `FixedBytes<N>` is expected
+    let field_ref = |value: TokenStream, ty: &Type| match ty {
+        Type::FixedBytes(..) if fixed_bytes_as_array => {
+            quote!(&::alloy_sol_types::private::FixedBytes(#value))
+        }
+        _ => quote!(&#value),
+    };
+    let topic_fields = fields
+        .iter()
+        .map(|f| {
+            let name = f.name.as_ref().unwrap();
+            field_ref(quote!(rust.#name), &f.ty)
+        })
+        .collect::<Vec<_>>();
+
     let eip712_encode_type_fns = expand_encode_type_fns(cx, fields, name);
 
     let tokenize_impl = expand_tokenize(fields);
@@ -51,10 +77,22 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         },
     };
 
+    let rlp: Option<TokenStream> = rlp.then(|| {
+        if_rlp! { expand_rlp(name, fields) }
+    });
+
+    let debug_fields = fields.iter().map(|f| {
+        let name = f.name.as_ref().unwrap();
+        (name.0.clone(), expand_type(&f.ty), field_ref(quote!(self.#name), &f.ty))
+    });
+    let debug = debug.then(|| expand_debug(&name.0, debug_fields));
     let attrs = attrs.iter();
-    let convert = expand_from_into_tuples(&name.0, fields);
+    let convert = expand_from_into_tuples_with(&name.0, fields, fixed_bytes_as_array);
     let name_s = name.as_string();
-    let fields = expand_fields(fields);
+    let decode_impl = expand_decode(&name_s, "decode", &max_bytes_checks);
+    let decode_params_impl = expand_decode(&name_s, "decode_params", &max_bytes_checks);
+    let decode_sequence_impl = expand_decode(&name_s, "decode_sequence", &max_bytes_checks);
+    let fields = expand_fields_with(fields, fixed_bytes_as_array);
 
     let doc = docs.then(|| attr::mk_doc(format!("```solidity\n{s}\n```")));
     let tokens = quote! {
@@ -70,6 +108,16 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         const _: () = {
             #convert
 
+            #debug
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::private::SolTypeDebug for #name {
+                #[inline]
+                fn fmt_debug(rust: &Self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Debug::fmt(rust, f)
+                }
+            }
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolValue for #name {
                 type SolType = Self;
@@ -121,11 +169,38 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                     <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::valid_token(token)
                 }
 
+                #[inline]
+                fn type_check(token: &Self::Token<'_>) -> ::alloy_sol_types::Result<()> {
+                    #(#max_bytes_checks)*
+                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::type_check(token)
+                }
+
                 #[inline]
                 fn detokenize(token: Self::Token<'_>) -> Self::RustType {
                     let tuple = <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::detokenize(token);
                     <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
                 }
+
+                #[inline]
+                fn abi_decode(data: &[u8], validate: bool) -> ::alloy_sol_types::Result<Self::RustType> {
+                    #decode_impl
+                }
+
+                #[inline]
+                fn abi_decode_params<'de>(data: &'de [u8], validate: bool) -> ::alloy_sol_types::Result<Self::RustType>
+                where
+                    Self::Token<'de>: ::alloy_sol_types::abi::TokenSeq<'de>,
+                {
+                    #decode_params_impl
+                }
+
+                #[inline]
+                fn abi_decode_sequence<'de>(data: &'de [u8], validate: bool) -> ::alloy_sol_types::Result<Self::RustType>
+                where
+                    Self::Token<'de>: ::alloy_sol_types::abi::TokenSeq<'de>,
+                {
+                    #decode_sequence_impl
+                }
             }
 
             #[automatically_derived]
@@ -145,7 +220,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 fn topic_preimage_length(rust: &Self::RustType) -> usize {
                     0usize
                     #(
-                        + <#field_types as ::alloy_sol_types::EventTopic>::topic_preimage_length(&rust.#field_names)
+                        + <#field_types as ::alloy_sol_types::EventTopic>::topic_preimage_length(#topic_fields)
                     )*
                 }
 
@@ -153,7 +228,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 fn encode_topic_preimage(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
                     out.reserve(<Self as ::alloy_sol_types::EventTopic>::topic_preimage_length(rust));
                     #(
-                        <#field_types as ::alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.#field_names, out);
+                        <#field_types as ::alloy_sol_types::EventTopic>::encode_topic_preimage(#topic_fields, out);
                     )*
                 }
 
@@ -166,11 +241,120 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                     )
                 }
             }
+
+            #rlp
         };
     };
     Ok(tokens)
 }
 
+/// Expands the body of an `abi_decode*` method of `SolType`, which names the
+/// struct in decoding errors.
+///
+/// If any field is annotated with `#[sol(max_bytes = <N>)]`, its length is
+/// checked on the decoded token before the value is copied out of the input,
+/// even if `validate` is `false`. When the struct is nested inside of another
+/// type, the lengths are only checked in `type_check`.
+fn expand_decode(name_s: &str, decode: &str, checks: &[TokenStream]) -> TokenStream {
+    let method = format_ident!("abi_{decode}");
+    let decode = format_ident!("{decode}");
+    let body = if checks.is_empty() {
+        quote! {
+            <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::#method(data, validate)
+                .map(<Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from)
+        }
+    } else {
+        quote! {
+            ::alloy_sol_types::abi::#decode::<Self::Token<'_>>(data, validate).and_then(|token| {
+                #(#checks)*
+                if validate {
+                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::type_check(&token)?;
+                }
+                Ok(<Self as ::alloy_sol_types::SolType>::detokenize(token))
+            })
+        }
+    };
+    quote! {
+        #body.map_err(|e| ::alloy_sol_types::Error::decode_failed(#name_s, e))
+    }
+}
+
+/// Expands the RLP `Encodable` and `Decodable` implementations, which encode the
+/// struct as a list of its fields, in order.
+///
+/// `bytes` fields are encoded as RLP strings rather than as lists of bytes.
+#[cfg(feature = "rlp")]
+fn expand_rlp(name: &ast::SolIdent, fields: &ast::Parameters<syn::token::Semi>) -> TokenStream {
+    let (field_names, encodables): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .map(|f| {
+            let name = f.name.as_ref().unwrap();
+            let encodable = match f.ty {
+                Type::Bytes(_) => quote!(&self.#name[..]),
+                _ => quote!(&self.#name),
+            };
+            (name, encodable)
+        })
+        .unzip();
+    let decodes = fields.iter().map(|f| match f.ty {
+        Type::Bytes(_) => quote! {
+            ::alloy_sol_types::private::alloy_rlp::Header::decode_bytes(buf, false)?.to_vec()
+        },
+        _ => quote!(::alloy_sol_types::private::alloy_rlp::Decodable::decode(buf)?),
+    });
+
+    let payload_length = quote! {
+        0usize #(+ ::alloy_sol_types::private::alloy_rlp::Encodable::length(#encodables))*
+    };
+    quote! {
+        #[automatically_derived]
+        impl ::alloy_sol_types::private::alloy_rlp::Encodable for #name {
+            #[inline]
+            fn length(&self) -> usize {
+                let payload_length = #payload_length;
+                payload_length + ::alloy_sol_types::private::alloy_rlp::length_of_length(payload_length)
+            }
+
+            fn encode(&self, out: &mut dyn ::alloy_sol_types::private::alloy_rlp::BufMut) {
+                let header = ::alloy_sol_types::private::alloy_rlp::Header {
+                    list: true,
+                    payload_length: #payload_length,
+                };
+                header.encode(out);
+                #(
+                    ::alloy_sol_types::private::alloy_rlp::Encodable::encode(#encodables, out);
+                )*
+            }
+        }
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::private::alloy_rlp::Decodable for #name {
+            fn decode(buf: &mut &[u8]) -> ::alloy_sol_types::private::alloy_rlp::Result<Self> {
+                let header = ::alloy_sol_types::private::alloy_rlp::Header::decode(buf)?;
+                if !header.list {
+                    return Err(::alloy_sol_types::private::alloy_rlp::Error::UnexpectedString);
+                }
+                if buf.len() < header.payload_length {
+                    return Err(::alloy_sol_types::private::alloy_rlp::Error::InputTooShort);
+                }
+
+                let started_len = buf.len();
+                let this = Self {
+                    #(#field_names: #decodes,)*
+                };
+                let consumed = started_len - buf.len();
+                if consumed != header.payload_length {
+                    return Err(::alloy_sol_types::private::alloy_rlp::Error::ListLengthMismatch {
+                        expected: header.payload_length,
+                        got: consumed,
+                    });
+                }
+                Ok(this)
+            }
+        }
+    }
+}
+
 fn expand_encode_type_fns(
     cx: &ExpCtxt<'_>,
     fields: &ast::Parameters<syn::token::Semi>,
@@ -281,6 +281,28 @@ pub(super) fn can_derive_builtin_traits(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
     }
 }
 
+/// Returns whether the Rust type generated for the given type is `Copy`.
+///
+/// Structs are only `Copy` if they have the `#[sol(copy)]` attribute, and UDVTs
+/// are `Copy` if their underlying type is.
+pub(super) fn can_derive_copy(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
+    match ty {
+        Type::String(_) | Type::Bytes(_) | Type::Mapping(_) => false,
+        Type::Array(a) => a.size().is_some() && can_derive_copy(cx, &a.ty),
+        Type::Tuple(tuple) => tuple.types.iter().all(|ty| can_derive_copy(cx, ty)),
+
+        Type::Custom(name) => match cx.try_item(name) {
+            Some(Item::Contract(_)) | Some(Item::Enum(_)) => true,
+            Some(Item::Struct(strukt)) => crate::attr::SolAttrs::parse(&strukt.attrs)
+                .map_or(false, |(attrs, _)| attrs.copy == Some(true)),
+            Some(Item::Udt(_)) => can_derive_copy(cx, cx.custom_type(name)),
+            _ => false,
+        },
+
+        _ => true,
+    }
+}
+
 /// Implements [`fmt::Display`] which formats a [`Type`] to its canonical
 /// representation. This is then used in function, error, and event selector
 /// generation.
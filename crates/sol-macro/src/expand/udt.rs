@@ -1,17 +1,24 @@
 //! [`ItemUdt`] expansion.
 
-use super::{ty::expand_rust_type, ExpCtxt};
+use super::{
+    ty::{self, expand_rust_type},
+    ExpCtxt,
+};
 use crate::expand::expand_type;
 use ast::ItemUdt;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::Result;
+use syn::{parse_quote, Result};
 
 pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
     let ItemUdt { name, ty, attrs, .. } = udt;
 
     let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.type_derives(&mut attrs, std::iter::once(ty), true);
+    if ty::can_derive_copy(cx, ty) {
+        attrs.push(parse_quote! { #[derive(Copy)] });
+    }
+    let debug = cx.debug(&mut attrs, sol_attrs.debug);
 
     let underlying_sol = expand_type(ty);
     let underlying_rust = expand_rust_type(ty);
@@ -28,12 +35,36 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
         }
     };
 
+    let debug = debug.then(|| {
+        let name_s = name.as_string();
+        quote! {
+            #[automatically_derived]
+            impl ::core::fmt::Debug for #name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_tuple(#name_s)
+                        .field(&::alloy_sol_types::private::DebugWith::<#underlying_sol>(&self.0))
+                        .finish()
+                }
+            }
+        }
+    });
+
     let tokens = quote! {
         #(#attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
         pub struct #name(#underlying_rust);
 
+        #debug
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::private::SolTypeDebug for #name {
+            #[inline]
+            fn fmt_debug(rust: &Self::RustType, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                <#underlying_sol as ::alloy_sol_types::private::SolTypeDebug>::fmt_debug(rust, f)
+            }
+        }
+
         #[automatically_derived]
         impl ::alloy_sol_types::private::SolTypeValue<#name> for #underlying_rust {
             #[inline]
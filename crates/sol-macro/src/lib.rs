@@ -49,6 +49,7 @@ mod json;
 ///
 /// [tests]: https://github.com/alloy-rs/core/tree/main/crates/sol-types/tests/doctests
 /// [`alloy-sol-types`]: https://docs.rs/alloy-sol-types
+/// [`alloy-rlp`]: https://docs.rs/alloy-rlp
 ///
 /// There are two main ways to use this macro:
 /// - you can [write Solidity code](#solidity), or provide a path to a Solidity file,
@@ -59,6 +60,14 @@ mod json;
 /// - relative file system paths are rooted at the `CARGO_MANIFEST_DIR` environment variable
 /// - no casing convention is enforced for any identifier,
 /// - unnamed arguments will be given a name based on their index in the list, e.g. `_0`, `_1`...
+/// - all generated types implement `Debug` in a human-readable format: addresses are checksummed,
+///   integers are formatted in decimal, and bytes in hex. `Debug` is removed from any
+///   `#[derive(...)]` or `#[cfg_attr(..., derive(...))]` attribute on the item, so this impl is
+///   also used with `#[derive(Debug)]` and `all_derives`. To implement `Debug` manually, or derive
+///   it instead, use `#[sol(debug = false)]`,
+/// - errors returned when decoding generated calls, return values, errors, events and structs are
+///   wrapped in `Error::DecodeFailed`, which contains the name of the type being decoded. Types
+///   nested inside of it, like struct fields, are not named,
 /// - a current limitation for certain items is that custom types, like structs, must be defined in
 ///   the same macro scope, otherwise a signature cannot be generated at compile time. You can bring
 ///   them in scope with a [Solidity type alias](#udvt-and-type-aliases).
@@ -98,8 +107,9 @@ mod json;
 ///   generated types, such as `From` impls and `as_<variant>` methods. May significantly increase
 ///   compile times due to all the extra generated code. This is the default behaviour of
 ///   [`abigen`][abigen]
-/// - `docs [ = <bool = true>]`: adds doc comments to all generated types. This is the default
-///   behaviour of [`abigen`][abigen]
+/// - `docs [ = <bool = true>]`: adds doc comments to all generated types, modules and statics.
+///   Methods in generated `impl` blocks are always documented. This is the default behaviour of
+///   [`abigen`][abigen]
 /// - `abi [ = <bool = false>]`: generates functions which return the dynamic ABI representation
 ///   (provided by [`alloy_json_abi`](https://docs.rs/alloy-json-abi)) of all the generated items.
 ///   Requires the `"json"` feature. For:
@@ -113,12 +123,33 @@ mod json;
 ///     - `pub fn errors() -> BTreeMap<String, Vec<Error>>`
 ///   - items: generates implementations of the `SolAbiExt` trait, alongside the existing
 ///     [`alloy-sol-types`] traits
+/// - `rlp [ = <bool = false>]`: generates implementations of the [`alloy-rlp`] `Encodable` and
+///   `Decodable` traits for structs, which encode the struct as an RLP list of its fields, in
+///   order. `bytes` fields are encoded as RLP strings. Requires the `"rlp"` feature.
+/// - `fixed_bytes_as_array [ = <bool = false>]`: generates `bytesN` struct fields as `[u8; N]`
+///   instead of `FixedBytes<N>`. Only applies to fields whose type is exactly `bytesN`, and not to
+///   nested types like `bytesN[]`. The encoding is unaffected.
+/// - `copy [ = <bool = false>]`: on a struct or function, derives `Copy` for the generated structs.
+///   All of their fields must be `Copy`, otherwise an error naming the first field that is not is
+///   emitted. `bytes`, `string`, dynamic arrays and structs without this attribute are not `Copy`.
+///   UDVTs are `Copy` if their underlying type is.
+/// - `debug [ = <bool = true>]`: implements `Debug` for the generated types in a human-readable
+///   format, removing `Debug` from their derives. Set it to `false` to keep a derived `Debug`, or
+///   to write your own impl. On a contract, this only applies to the generated `Calls`, `Errors`
+///   and `Events` enums.
 /// - `bytecode = <hex string literal>`: specifies the creation/init bytecode of a contract. This
-///   will emit a `static` item with the specified bytes.
+///   will emit a `static` item with the specified bytes, and a `deploy_builder` function which
+///   takes the constructor arguments and returns the bytecode followed by their ABI encoding.
 /// - `deployed_bytecode = <hex string literal>`: specifies the deployed bytecode of a contract.
 ///   This will emit a `static` item with the specified bytes.
 /// - `type_check = <string literal>`: specifies a function to be used to check an User Defined
 ///   Type.
+/// - `max_bytes = <integer literal>`: on a `bytes` or `string` function parameter or struct field,
+///   specifies the maximum length in bytes of the encoded value, which is checked before it is
+///   copied out of the input. Decoding a call or struct whose value exceeds it fails with
+///   `Error::LimitExceeded`. When the struct is nested inside of another type, like a call
+///   parameter, its fields are only checked when decoding with `validate` set to `true`. This
+///   attribute is not supported anywhere else.
 ///
 /// ### Structs and enums
 ///
@@ -142,6 +173,12 @@ mod json;
 ///
 /// Functions generate two structs that implement `SolCall`: `<name>Call` for
 /// the function arguments, and `<name>Return` for the return values.
+/// `<name>Call` also has a `new_args` constructor, which takes each argument
+/// separately, and `<name>Return` has an `abi_encode` method, which encodes the
+/// return values as returned by the function.
+///
+/// The fields of `<name>Return` are named after the function's named return
+/// values, and these names are also available in `SolCall::RETURN_NAMES`.
 ///
 /// In the case of overloaded functions, an underscore and the index of the
 /// function will be appended to `<name>` (like `foo_0`, `foo_1`...) for
@@ -150,6 +187,16 @@ mod json;
 /// E.g. if there are two functions named `foo`, the generated types will be
 /// `foo_0Call` and `foo_1Call`, each of which will implement `SolCall`
 /// with their respective signatures.
+///
+/// If a function has no return values, decoding non-empty return data with
+/// `abi_decode_returns` fails with `Error::UnexpectedReturnData`, as this data
+/// is most likely the result of a revert. To decode data which may either be
+/// the return values or the revert data of one of the contract's errors, use
+/// `SolCall::abi_decode_response`.
+///
+/// If the `async` feature is enabled, `<name>Call` structs will also have an
+/// `async fn call(&self, provider, to)` method, which executes the call using a
+/// `CallProvider` and decodes the returned data into `<name>Return`.
 /// ```ignore
 #[cfg_attr(doc, doc = include_str!("../doctests/function_like.rs"))]
 /// ```
@@ -161,7 +208,8 @@ mod json;
 /// Note that events have special encoding rules in Solidity. For example,
 /// `string indexed` will be encoded in the topics as its `bytes32` Keccak-256
 /// hash, and as such the generated field for this argument will be `bytes32`,
-/// and not `string`.
+/// and not `string`. The same applies to all indexed arrays, tuples and
+/// structs, even if they are not dynamically sized.
 /// ```ignore
 #[cfg_attr(doc, doc = include_str!("../doctests/events.rs"))]
 /// ```
@@ -174,6 +222,15 @@ mod json;
 /// - functions: `<contract_name>Calls`
 /// - errors: `<contract_name>Errors`
 /// - events: `<contract_name>Events`
+///
+/// The module also contains the `decode_call(data, validate)` and
+/// `decode_event(log, validate)` functions, if the contract has any functions
+/// or events respectively, which decode calldata or a log into the
+/// corresponding container enum.
+///
+/// Since each contract generates its own module, contracts can declare items with the same name,
+/// such as a shared error or event definition, without conflicts. Overloaded functions and events
+/// are also disambiguated separately for each contract.
 /// ```ignore
 #[cfg_attr(doc, doc = include_str!("../doctests/contracts.rs"))]
 /// ```
@@ -68,6 +68,8 @@ impl<'de> Decoder<'de> {
     /// If `validate` is true, the decoder will check that the bytes conform to
     /// expected type limitations, and that the decoded values can be re-encoded
     /// to an identical bytestring.
+    ///
+    /// This only borrows the buffer, and does not allocate.
     #[inline]
     pub const fn new(buf: &'de [u8], validate: bool) -> Self {
         Self { buf, offset: 0, validate }
@@ -63,6 +63,21 @@ impl Encoder {
         crate::impl_core::into_flattened::<u8, 32>(unsafe { mem::transmute(self.buf) })
     }
 
+    /// Returns the encoded bytes without consuming the encoder.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `#[repr(transparent)] FixedBytes<N>([u8; N])`
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast(), self.buf.len() * 32) }
+    }
+
+    /// Clears the encoder, keeping its allocated capacity so that it can be
+    /// reused.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.suffix_offset.clear();
+    }
+
     /// Determine the current suffix offset.
     ///
     /// # Panics
@@ -9,7 +9,7 @@
 
 use crate::abi;
 use alloc::{borrow::Cow, boxed::Box, string::String};
-use alloy_primitives::LogData;
+use alloy_primitives::{Bytes, LogData};
 use core::fmt;
 
 /// ABI result type.
@@ -61,6 +61,34 @@ pub enum Error {
         selector: alloy_primitives::FixedBytes<4>,
     },
 
+    /// A `string` value was not valid UTF-8.
+    InvalidUtf8(core::str::Utf8Error),
+
+    /// A decoded `bytes` or `string` value exceeded its maximum length.
+    LimitExceeded {
+        /// The name of the value.
+        name: &'static str,
+        /// The maximum length, in bytes.
+        limit: usize,
+        /// The actual length, in bytes.
+        len: usize,
+    },
+
+    /// A contract call reverted with the given revert data.
+    Reverted(Bytes),
+
+    /// A function without return values returned non-empty data, which is
+    /// most likely revert data.
+    UnexpectedReturnData(Bytes),
+
+    /// Decoding a type generated by [`sol!`](crate::sol) failed.
+    DecodeFailed {
+        /// The name of the type.
+        type_name: &'static str,
+        /// The underlying error.
+        source: Box<Error>,
+    },
+
     /// Hex error.
     FromHexError(hex::FromHexError),
 
@@ -72,7 +100,9 @@ pub enum Error {
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
+            Self::InvalidUtf8(e) => Some(e),
             Self::FromHexError(e) => Some(e),
+            Self::DecodeFailed { source, .. } => Some(&**source),
             _ => None,
         }
     }
@@ -96,6 +126,28 @@ impl fmt::Display for Error {
             Self::UnknownSelector { name, selector } => {
                 write!(f, "unknown selector `{selector}` for {name}")
             }
+            Self::InvalidUtf8(e) => write!(f, "invalid UTF-8 string: {e}"),
+            Self::LimitExceeded { name, limit, len } => {
+                write!(f, "`{name}` exceeds the maximum length of {limit} bytes: {len}")
+            }
+            Self::Reverted(data) => {
+                f.write_str("execution reverted")?;
+                match crate::decode_revert_reason(data) {
+                    Some(reason) => write!(f, ": {reason}"),
+                    None if data.is_empty() => Ok(()),
+                    None => write!(f, " with data: {data}"),
+                }
+            }
+            Self::UnexpectedReturnData(data) => {
+                f.write_str("unexpected return data for function without return values")?;
+                match crate::decode_revert_reason(data) {
+                    Some(reason) => write!(f, ", probably reverted: {reason}"),
+                    None => write!(f, ": {data}"),
+                }
+            }
+            Self::DecodeFailed { type_name, source } => {
+                write!(f, "could not decode {type_name}: {source}")
+            }
             Self::FromHexError(e) => e.fmt(f),
             Self::Other(e) => f.write_str(e),
         }
@@ -136,6 +188,19 @@ impl Error {
     pub fn unknown_selector(name: &'static str, selector: [u8; 4]) -> Self {
         Self::UnknownSelector { name, selector: selector.into() }
     }
+
+    /// Instantiates a new [`Error::DecodeFailed`] with the provided type name
+    /// and underlying error.
+    #[cold]
+    pub fn decode_failed(type_name: &'static str, source: Self) -> Self {
+        Self::DecodeFailed { type_name, source: Box::new(source) }
+    }
+}
+
+impl From<core::str::Utf8Error> for Error {
+    fn from(value: core::str::Utf8Error) -> Self {
+        Self::InvalidUtf8(value)
+    }
 }
 
 impl From<hex::FromHexError> for Error {
@@ -180,11 +180,17 @@ pub use ext::JsonAbiExt;
 
 mod impl_core;
 
+#[cfg(feature = "async")]
+mod provider;
+#[cfg(feature = "async")]
+pub use provider::CallProvider;
+
 mod types;
 pub use types::{
-    data_type as sol_data, decode_revert_reason, ContractError, EventTopic, GenericContractError,
-    GenericRevertReason, Panic, PanicKind, Revert, Selectors, SolCall, SolConstructor, SolEnum,
-    SolError, SolEvent, SolEventInterface, SolInterface, SolStruct, SolType, SolValue, TopicList,
+    data_type as sol_data, decode_revert_reason, matches_topic0, ContractError, EventTopic,
+    EventTopicFilter, GenericContractError, GenericRevertReason, Panic, PanicKind, Revert,
+    Selectors, SolCall, SolConstructor, SolEnum, SolError, SolEvent, SolEventInterface,
+    SolInterface, SolStruct, SolType, SolValue, TopicList,
 };
 
 pub mod utils;
@@ -202,7 +208,10 @@ pub use alloy_sol_macro::sol;
 #[doc(hidden)]
 #[allow(missing_debug_implementations)]
 pub mod private {
-    pub use super::utils::{just_ok, next_multiple_of_32, words_for, words_for_len};
+    pub use super::{
+        types::{DebugWith, SolTypeDebug},
+        utils::{just_ok, next_multiple_of_32, words_for, words_for_len},
+    };
     pub use alloc::{
         borrow::{Cow, ToOwned},
         boxed::Box,
@@ -212,8 +221,8 @@ pub mod private {
         vec::Vec,
     };
     pub use alloy_primitives::{
-        bytes, keccak256, Address, Bytes, FixedBytes, Function, LogData, Signed, Uint, B256, I256,
-        U256,
+        bytes, keccak256, Address, Bytes, FixedBytes, Function, Log, LogData, Signed, Uint, B256,
+        I256, U256,
     };
     pub use core::{
         borrow::{Borrow, BorrowMut},
@@ -229,6 +238,12 @@ pub mod private {
     #[cfg(feature = "json")]
     pub use alloy_json_abi;
 
+    #[cfg(feature = "rlp")]
+    pub use alloy_primitives::private::alloy_rlp;
+
+    #[cfg(feature = "async")]
+    pub use super::provider::call;
+
     /// An ABI-encodable is any type that may be encoded via a given `SolType`.
     ///
     /// The `SolType` trait contains encoding logic for a single associated
@@ -256,5 +271,25 @@ pub mod private {
         U256::from_limbs([n, 0, 0, 0])
     }
 
+    /// Decodes a single one-word value, like the return value of `balanceOf`.
+    ///
+    /// This is equivalent to decoding `(T,)` as a sequence, except that the
+    /// re-encoding check when validating, which allocates, is replaced by a
+    /// length check.
+    #[inline]
+    pub fn decode_word<T: super::SolType>(
+        data: &[u8],
+        validate: bool,
+    ) -> super::Result<T::RustType> {
+        let token = super::abi::Decoder::new(data, validate).decode::<T::Token<'_>>()?;
+        if validate {
+            T::type_check(&token)?;
+            if data.len() != super::Word::len_bytes() {
+                return Err(super::Error::ReserMismatch);
+            }
+        }
+        Ok(T::detokenize(token))
+    }
+
     pub struct AssertTypeEq<T>(pub T);
 }
@@ -0,0 +1,70 @@
+use crate::{ContractError, Error, Result, SolCall, SolInterface};
+use alloy_primitives::{Address, Bytes};
+use core::future::Future;
+
+/// A minimal provider which can execute `eth_call`s.
+///
+/// This allows [`sol!`](crate::sol!)-generated bindings to execute calls
+/// without depending on a specific RPC client. With the `async` feature
+/// enabled, every generated call struct gets an inherent `call` method which
+/// ABI-encodes the call, executes it using this trait, and decodes the return
+/// data into the call's return struct, or the revert data into a
+/// [`ContractError`] of the contract's errors.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{Address, Bytes};
+/// use alloy_sol_types::{CallProvider, Result};
+/// use core::future::{ready, Ready};
+///
+/// /// A provider that always returns the same data.
+/// struct Static(Bytes);
+///
+/// impl CallProvider for Static {
+///     type CallFuture<'a> = Ready<Result<Bytes>>;
+///
+///     fn eth_call(&self, _to: Address, _data: Bytes) -> Self::CallFuture<'_> {
+///         ready(Ok(self.0.clone()))
+///     }
+/// }
+/// ```
+pub trait CallProvider {
+    /// The future returned by [`eth_call`](Self::eth_call).
+    type CallFuture<'a>: Future<Output = Result<Bytes>> + 'a
+    where
+        Self: 'a;
+
+    /// Executes a message call to `to` with the given calldata, and returns the
+    /// raw return data.
+    ///
+    /// If the call reverts, implementations should return
+    /// [`Error::Reverted`](crate::Error::Reverted) with the raw revert data.
+    fn eth_call(&self, to: Address, data: Bytes) -> Self::CallFuture<'_>;
+}
+
+/// Implementation of the generated `call` methods.
+///
+/// Revert data is decoded as one of the errors in `E`, or as a [`Revert`](crate::Revert) or
+/// [`Panic`](crate::Panic). If it cannot be decoded, the raw
+/// [`Error::Reverted`] is returned instead.
+#[doc(hidden)]
+pub async fn call<C, E, P>(
+    call: &C,
+    provider: &P,
+    to: Address,
+) -> Result<core::result::Result<C::Return, ContractError<E>>>
+where
+    C: SolCall,
+    E: SolInterface,
+    P: CallProvider + ?Sized,
+{
+    match provider.eth_call(to, call.abi_encode().into()).await {
+        Ok(data) => C::abi_decode_returns(&data, true).map(Ok),
+        Err(Error::Reverted(data)) => match ContractError::<E>::abi_decode(&data, true) {
+            Ok(error) => Ok(Err(error)),
+            Err(_) => Err(Error::Reverted(data)),
+        },
+        Err(e) => Err(e),
+    }
+}
@@ -8,7 +8,7 @@
 
 #![allow(missing_copy_implementations, missing_debug_implementations)]
 
-use crate::{abi::token::*, private::SolTypeValue, utils, SolType, Word};
+use crate::{abi::token::*, private::SolTypeValue, utils, Result, SolType, Word};
 use alloc::{borrow::Cow, string::String as RustString, vec::Vec};
 use alloy_primitives::{
     keccak256, Address as RustAddress, FixedBytes as RustFixedBytes, Function as RustFunction,
@@ -62,6 +62,10 @@ impl SolType for Bool {
 }
 
 /// Int - `intX`
+///
+/// The Rust representation may be larger than `intX`, e.g. `i32` for `int24`.
+/// Encoding a value outside of the range of `intX` panics in debug mode, and
+/// truncates it to its lower `X` bits otherwise.
 pub struct Int<const BITS: usize>;
 
 impl<T, const BITS: usize> SolTypeValue<Int<BITS>> for T
@@ -119,6 +123,10 @@ where
 }
 
 /// Uint - `uintX`
+///
+/// The Rust representation may be larger than `uintX`, e.g. `u32` for
+/// `uint24`. Encoding a value outside of the range of `uintX` panics in debug
+/// mode, and truncates it to its lower `X` bits otherwise.
 pub struct Uint<const BITS: usize>;
 
 impl<const BITS: usize, T> SolTypeValue<Uint<BITS>> for T
@@ -355,6 +363,11 @@ impl SolType for Bytes {
 }
 
 /// String - `string`
+///
+/// Solidity strings are arbitrary bytes, and are not guaranteed to be valid
+/// UTF-8. When decoding with `validate = true`, invalid UTF-8 results in an
+/// [`Error::InvalidUtf8`](crate::Error::InvalidUtf8) error. Otherwise, the
+/// value is decoded lossily with [`String::from_utf8_lossy`](RustString::from_utf8_lossy).
 pub struct String;
 
 impl<T: ?Sized + AsRef<str>> SolTypeValue<String> for T {
@@ -400,6 +413,11 @@ impl SolType for String {
         core::str::from_utf8(token.as_slice()).is_ok()
     }
 
+    #[inline]
+    fn type_check(token: &Self::Token<'_>) -> Result<()> {
+        core::str::from_utf8(token.as_slice()).map(drop).map_err(Into::into)
+    }
+
     #[inline]
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         // NOTE: We're decoding strings using lossy UTF-8 decoding to
@@ -543,6 +561,11 @@ impl<T: SolType> SolType for Array<T> {
         token.0.iter().all(T::valid_token)
     }
 
+    #[inline]
+    fn type_check(token: &Self::Token<'_>) -> Result<()> {
+        token.0.iter().try_for_each(T::type_check)
+    }
+
     #[inline]
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0.into_iter().map(T::detokenize).collect()
@@ -669,6 +692,11 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
         token.as_array().iter().all(T::valid_token)
     }
 
+    #[inline]
+    fn type_check(token: &Self::Token<'_>) -> Result<()> {
+        token.as_array().iter().try_for_each(T::type_check)
+    }
+
     #[inline]
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0.map(T::detokenize)
@@ -763,6 +791,12 @@ macro_rules! tuple_impls {
                 $(<$ty as SolType>::valid_token($ty))&&+
             }
 
+            fn type_check(token: &Self::Token<'_>) -> Result<()> {
+                let ($($ty,)+) = token;
+                $(<$ty as SolType>::type_check($ty)?;)+
+                Ok(())
+            }
+
             fn detokenize(token: Self::Token<'_>) -> Self::RustType {
                 let ($($ty,)+) = token;
                 ($(
@@ -938,12 +972,37 @@ macro_rules! supported_int {
     )+};
 }
 
+macro_rules! debug_assert_int_range {
+    ($name:expr, $value:expr, $fits:expr) => {
+        debug_assert!($fits, "value out of range for {}: {}", $name, $value)
+    };
+}
+
+/// Returns `true` if the big-endian `bytes` of an unsigned integer are zero
+/// in the first `skip` bytes, which are not encoded.
+#[inline]
+fn fits_uint(bytes: &[u8], skip: usize) -> bool {
+    bytes[..skip].iter().all(|byte| *byte == 0)
+}
+
+/// Returns `true` if the big-endian `bytes` of a signed integer are a sign
+/// extension of the remaining bytes in the first `skip` bytes, which are not
+/// encoded.
+#[inline]
+fn fits_int(bytes: &[u8], skip: usize) -> bool {
+    let sign_extension = (bytes[0] & 0x80 == 0x80) as u8 * 0xff;
+    bytes[..skip].iter().all(|byte| *byte == sign_extension)
+        && (bytes[skip] ^ sign_extension) & 0x80 == 0
+}
+
 macro_rules! int_impls {
     (@primitive_int $ity:ident) => {
         #[inline]
         fn tokenize_int(int: $ity) -> WordToken {
+            let bytes = int.to_be_bytes();
+            debug_assert_int_range!(Self::INT_NAME, int, fits_int(&bytes, Self::SKIP_BYTES));
             let mut word = [int.is_negative() as u8 * 0xff; 32];
-            word[Self::WORD_MSB..].copy_from_slice(&int.to_be_bytes()[Self::SKIP_BYTES..]);
+            word[Self::WORD_MSB..].copy_from_slice(&bytes[Self::SKIP_BYTES..]);
             WordToken::new(word)
         }
 
@@ -960,14 +1019,18 @@ macro_rules! int_impls {
 
         #[inline]
         fn encode_packed_to_int(int: $ity, out: &mut Vec<u8>) {
-            out.extend_from_slice(&int.to_be_bytes()[Self::SKIP_BYTES..]);
+            let bytes = int.to_be_bytes();
+            debug_assert_int_range!(Self::INT_NAME, int, fits_int(&bytes, Self::SKIP_BYTES));
+            out.extend_from_slice(&bytes[Self::SKIP_BYTES..]);
         }
     };
     (@primitive_uint $uty:ident) => {
         #[inline]
         fn tokenize_uint(uint: $uty) -> WordToken {
+            let bytes = uint.to_be_bytes();
+            debug_assert_int_range!(Self::UINT_NAME, uint, fits_uint(&bytes, Self::SKIP_BYTES));
             let mut word = Word::ZERO;
-            word[Self::WORD_MSB..].copy_from_slice(&uint.to_be_bytes()[Self::SKIP_BYTES..]);
+            word[Self::WORD_MSB..].copy_from_slice(&bytes[Self::SKIP_BYTES..]);
             WordToken(word)
         }
 
@@ -983,15 +1046,19 @@ macro_rules! int_impls {
 
         #[inline]
         fn encode_packed_to_uint(uint: $uty, out: &mut Vec<u8>) {
-            out.extend_from_slice(&uint.to_be_bytes()[Self::SKIP_BYTES..]);
+            let bytes = uint.to_be_bytes();
+            debug_assert_int_range!(Self::UINT_NAME, uint, fits_uint(&bytes, Self::SKIP_BYTES));
+            out.extend_from_slice(&bytes[Self::SKIP_BYTES..]);
         }
     };
 
     (@big_int $ity:ident) => {
         #[inline]
         fn tokenize_int(int: $ity) -> WordToken {
+            let bytes = int.to_be_bytes::<32>();
+            debug_assert_int_range!(Self::INT_NAME, int, fits_int(&bytes, Self::SKIP_BYTES));
             let mut word = [int.is_negative() as u8 * 0xff; 32];
-            word[Self::WORD_MSB..].copy_from_slice(&int.to_be_bytes::<32>()[Self::SKIP_BYTES..]);
+            word[Self::WORD_MSB..].copy_from_slice(&bytes[Self::SKIP_BYTES..]);
             WordToken::new(word)
         }
 
@@ -1008,14 +1075,18 @@ macro_rules! int_impls {
 
         #[inline]
         fn encode_packed_to_int(int: $ity, out: &mut Vec<u8>) {
-            out.extend_from_slice(&int.to_be_bytes::<32>()[Self::SKIP_BYTES..]);
+            let bytes = int.to_be_bytes::<32>();
+            debug_assert_int_range!(Self::INT_NAME, int, fits_int(&bytes, Self::SKIP_BYTES));
+            out.extend_from_slice(&bytes[Self::SKIP_BYTES..]);
         }
     };
     (@big_uint $uty:ident) => {
         #[inline]
         fn tokenize_uint(uint: $uty) -> WordToken {
+            let bytes = uint.to_be_bytes::<32>();
+            debug_assert_int_range!(Self::UINT_NAME, uint, fits_uint(&bytes, Self::SKIP_BYTES));
             let mut word = Word::ZERO;
-            word[Self::WORD_MSB..].copy_from_slice(&uint.to_be_bytes::<32>()[Self::SKIP_BYTES..]);
+            word[Self::WORD_MSB..].copy_from_slice(&bytes[Self::SKIP_BYTES..]);
             WordToken(word)
         }
 
@@ -1028,7 +1099,9 @@ macro_rules! int_impls {
 
         #[inline]
         fn encode_packed_to_uint(uint: $uty, out: &mut Vec<u8>) {
-            out.extend_from_slice(&uint.to_be_bytes::<32>()[Self::SKIP_BYTES..]);
+            let bytes = uint.to_be_bytes::<32>();
+            debug_assert_int_range!(Self::UINT_NAME, uint, fits_uint(&bytes, Self::SKIP_BYTES));
+            out.extend_from_slice(&bytes[Self::SKIP_BYTES..]);
         }
     };
 }
@@ -1248,6 +1321,15 @@ mod tests {
         roundtrip_i256(Int<256>: I256);
     }
 
+    proptest::proptest! {
+        #[test]
+        #[cfg_attr(miri, ignore = "doesn't run in isolation and would take too long")]
+        fn roundtrip_u256_edges(i in alloy_primitives::strategies::uint::<256, 4>()) {
+            let encoded = Uint::<256>::abi_encode(&i);
+            proptest::prop_assert_eq!(Uint::<256>::abi_decode(&encoded, true), Ok(i));
+        }
+    }
+
     #[test]
     fn tokenize_uint() {
         macro_rules! test {
@@ -1266,22 +1348,50 @@ mod tests {
             )+};
         }
 
-        let word: Word = Word::new(core::array::from_fn(|i| i as u8 + 1));
+        let word = |skip: usize| -> Word {
+            Word::new(core::array::from_fn(|i| if i < skip { 0 } else { i as u8 + 1 }))
+        };
 
         test! {
              8: 0x00u8 => "0000000000000000000000000000000000000000000000000000000000000000",
              8: 0x01u8 => "0000000000000000000000000000000000000000000000000000000000000001",
-            24: 0x01020304u32 => "0000000000000000000000000000000000000000000000000000000000020304",
+            24: 0x020304u32 => "0000000000000000000000000000000000000000000000000000000000020304",
             32: 0x01020304u32 => "0000000000000000000000000000000000000000000000000000000001020304",
-            56: 0x0102030405060708u64 => "0000000000000000000000000000000000000000000000000002030405060708",
+            56: 0x02030405060708u64 => "0000000000000000000000000000000000000000000000000002030405060708",
             64: 0x0102030405060708u64 => "0000000000000000000000000000000000000000000000000102030405060708",
 
-            160: word => "0000000000000000000000000d0e0f101112131415161718191a1b1c1d1e1f20",
-            200: word => "0000000000000008090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
-            256: word => "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+            160: word(12) => "0000000000000000000000000d0e0f101112131415161718191a1b1c1d1e1f20",
+            200: word(7) => "0000000000000008090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+            256: word(0) => "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
         }
     }
 
+    #[test]
+    fn int_ranges() {
+        assert!(fits_uint(&0x00ffffffu32.to_be_bytes(), 1));
+        assert!(!fits_uint(&0x01000000u32.to_be_bytes(), 1));
+        assert!(fits_int(&0x007fffffi32.to_be_bytes(), 1));
+        assert!(fits_int(&(-0x00800000i32).to_be_bytes(), 1));
+        assert!(!fits_int(&0x00800000i32.to_be_bytes(), 1));
+        assert!(!fits_int(&(-0x00800001i32).to_be_bytes(), 1));
+        assert!(fits_int(&i8::MIN.to_be_bytes(), 0));
+        assert!(fits_int(&I256::MAX.to_be_bytes::<32>(), 0));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "value out of range for uint24: 16777216"]
+    fn tokenize_uint_out_of_range() {
+        <Uint<24>>::abi_encode(&0x01000000);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "value out of range for int24: -8388609"]
+    fn tokenize_int_out_of_range() {
+        <Int<24>>::abi_encode_packed(&-0x00800001);
+    }
+
     #[test]
     fn detokenize_ints() {
         /*
@@ -1446,4 +1556,27 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn decode_invalid_utf8_string() {
+        // `string` with the bytes `0x61ff62`
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000003"
+            "61ff620000000000000000000000000000000000000000000000000000000000"
+        );
+
+        assert_eq!(String::abi_decode(&data, false).unwrap(), "a\u{FFFD}b");
+        assert!(matches!(String::abi_decode(&data, true), Err(crate::Error::InvalidUtf8(_))));
+
+        // nested strings propagate the error
+        let mut nested = <(Array<String>,)>::abi_encode_params(&(vec!["a"],));
+        let len = nested.len();
+        nested[len - 32] = 0xff;
+        assert_eq!(<(Array<String>,)>::abi_decode_params(&nested, false).unwrap().0, ["\u{FFFD}"]);
+        assert!(matches!(
+            <(Array<String>,)>::abi_decode_params(&nested, true),
+            Err(crate::Error::InvalidUtf8(_))
+        ));
+    }
 }
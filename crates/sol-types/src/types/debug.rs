@@ -0,0 +1,157 @@
+//! Human-readable `Debug` formatting for Solidity values.
+
+use crate::{
+    sol_data::{
+        Address, Array, Bool, ByteCount, Bytes, FixedArray, FixedBytes, Function, Int, IntBitCount,
+        String, SupportedFixedBytes, SupportedInt, Uint,
+    },
+    SolType,
+};
+use core::fmt;
+
+/// Formats a Solidity type's Rust value in a human-readable way.
+///
+/// This is used to implement [`Debug`](fmt::Debug) for the types generated by
+/// the [`sol!`](crate::sol!) macro:
+/// - `address` is formatted as a checksummed hex string;
+/// - integers are formatted in decimal;
+/// - `bytes` and `bytesN` are formatted as hex strings.
+///
+/// **Note:** this trait is an implementation detail. As such, it should not be
+/// implemented directly.
+pub trait SolTypeDebug: SolType {
+    /// Formats the given value.
+    fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Implements [`Debug`](fmt::Debug) for a Rust value using [`SolTypeDebug`].
+pub struct DebugWith<'a, T: SolTypeDebug>(pub &'a T::RustType);
+
+impl<T: SolTypeDebug> fmt::Debug for DebugWith<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt_debug(self.0, f)
+    }
+}
+
+macro_rules! debug_impls {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl SolTypeDebug for $ty {
+            #[inline]
+            fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(rust, f)
+            }
+        }
+    )+};
+}
+
+debug_impls!(Bool, Function, String);
+
+impl SolTypeDebug for Bytes {
+    #[inline]
+    fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode_prefixed(rust))
+    }
+}
+
+impl SolTypeDebug for Address {
+    #[inline]
+    fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(rust.to_checksum_buffer(None).as_str())
+    }
+}
+
+impl<const BITS: usize> SolTypeDebug for Int<BITS>
+where
+    IntBitCount<BITS>: SupportedInt,
+{
+    #[inline]
+    fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(rust, f)
+    }
+}
+
+impl<const BITS: usize> SolTypeDebug for Uint<BITS>
+where
+    IntBitCount<BITS>: SupportedInt,
+{
+    #[inline]
+    fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(rust, f)
+    }
+}
+
+impl<const N: usize> SolTypeDebug for FixedBytes<N>
+where
+    ByteCount<N>: SupportedFixedBytes,
+{
+    #[inline]
+    fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(rust, f)
+    }
+}
+
+impl<T: SolTypeDebug> SolTypeDebug for Array<T> {
+    fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(rust.iter().map(DebugWith::<T>)).finish()
+    }
+}
+
+impl<T: SolTypeDebug, const N: usize> SolTypeDebug for FixedArray<T, N> {
+    fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(rust.iter().map(DebugWith::<T>)).finish()
+    }
+}
+
+impl SolTypeDebug for () {
+    #[inline]
+    fn fmt_debug((): &(), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("()")
+    }
+}
+
+macro_rules! tuple_impls {
+    ($count:literal $($ty:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($ty: SolTypeDebug,)+> SolTypeDebug for ($($ty,)+) {
+            fn fmt_debug(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ($($ty,)+) = rust;
+                f.debug_tuple("")
+                    $(.field(&DebugWith::<$ty>($ty)))+
+                    .finish()
+            }
+        }
+    };
+}
+
+all_the_tuples!(tuple_impls);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, Address as RustAddress, U256};
+
+    fn debug<T: SolTypeDebug>(rust: &T::RustType) -> alloc::string::String {
+        format!("{:?}", DebugWith::<T>(rust))
+    }
+
+    #[test]
+    fn primitives() {
+        let addr = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        assert_eq!(debug::<Address>(&addr), "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+        assert_eq!(debug::<Uint<256>>(&U256::from(1_000_000)), "1000000");
+        assert_eq!(debug::<Int<8>>(&-1), "-1");
+        assert_eq!(debug::<Bytes>(&vec![0xde, 0xad]), "0xdead");
+        assert_eq!(debug::<FixedBytes<2>>(&[0xbe, 0xef].into()), "0xbeef");
+        assert_eq!(debug::<String>(&"a".into()), "\"a\"");
+    }
+
+    #[test]
+    fn compound() {
+        let value = (vec![RustAddress::ZERO], [U256::from(1), U256::from(2)], true);
+        assert_eq!(
+            debug::<(Array<Address>, FixedArray<Uint<256>, 2>, Bool)>(&value),
+            "([0x0000000000000000000000000000000000000000], [1, 2], true)"
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::SolEvent;
+use alloc::vec::Vec;
+use alloy_primitives::B256;
+
+/// Returns `true` if the first topic (`topic0`) of a log is `signature_hash`.
+///
+/// Note that this can only match non-anonymous events, as anonymous events do
+/// not emit their signature hash.
+#[inline]
+pub fn matches_topic0(signature_hash: &B256, topics: &[B256]) -> bool {
+    topics.first() == Some(signature_hash)
+}
+
+/// A set of event signature hashes, which matches logs whose first topic
+/// (`topic0`) is any one of them.
+///
+/// This can be used to subscribe to multiple events at once, by passing
+/// [`topic0`](Self::topic0) as the first topic of an `eth_getLogs` filter.
+///
+/// An empty filter does not match any log.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_types::{sol, EventTopicFilter, SolEvent};
+///
+/// sol! {
+///     event Transfer(address indexed from, address indexed to, uint256 value);
+///     event Approval(address indexed owner, address indexed spender, uint256 value);
+/// }
+///
+/// let filter = EventTopicFilter::new().event::<Transfer>().event::<Approval>();
+/// assert_eq!(filter.topic0(), [Transfer::SIGNATURE_HASH, Approval::SIGNATURE_HASH]);
+/// assert!(filter.matches(&[Approval::SIGNATURE_HASH]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EventTopicFilter {
+    topic0: Vec<B256>,
+}
+
+impl FromIterator<B256> for EventTopicFilter {
+    fn from_iter<T: IntoIterator<Item = B256>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::new(), Self::signature_hash)
+    }
+}
+
+impl From<EventTopicFilter> for Vec<B256> {
+    #[inline]
+    fn from(filter: EventTopicFilter) -> Self {
+        filter.topic0
+    }
+}
+
+impl EventTopicFilter {
+    /// Creates a new empty filter.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { topic0: Vec::new() }
+    }
+
+    /// Adds the signature hash of the event `E` to the filter.
+    ///
+    /// Anonymous events do not emit their signature hash, so they are
+    /// ignored.
+    #[inline]
+    pub fn event<E: SolEvent>(self) -> Self {
+        if E::ANONYMOUS {
+            self
+        } else {
+            self.signature_hash(E::SIGNATURE_HASH)
+        }
+    }
+
+    /// Adds an event signature hash to the filter, if it is not already
+    /// present.
+    pub fn signature_hash(mut self, hash: B256) -> Self {
+        if !self.topic0.contains(&hash) {
+            self.topic0.push(hash);
+        }
+        self
+    }
+
+    /// Returns the `topic0` candidates, in the order in which they were
+    /// added.
+    #[inline]
+    pub fn topic0(&self) -> &[B256] {
+        &self.topic0
+    }
+
+    /// Returns `true` if the filter contains no signature hashes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.topic0.is_empty()
+    }
+
+    /// Returns `true` if the first topic of a log is any of the signature
+    /// hashes in this filter.
+    #[inline]
+    pub fn matches(&self, topics: &[B256]) -> bool {
+        topics.first().map_or(false, |topic0| self.topic0.contains(topic0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sol;
+    use alloy_primitives::{Address, U256};
+
+    sol! {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Approval(address indexed owner, address indexed spender, uint256 value);
+        event Deposit(address indexed owner, uint256 value);
+        event Sync(uint256 value) anonymous;
+    }
+
+    fn encode_topics<E: SolEvent>(event: &E) -> Vec<B256> {
+        event.encode_topics().into_iter().map(|topic| topic.0).collect()
+    }
+
+    #[test]
+    fn matches_event_set() {
+        let filter = EventTopicFilter::new()
+            .event::<Transfer>()
+            .event::<Approval>()
+            .event::<Transfer>()
+            .event::<Sync>();
+        assert_eq!(filter.topic0(), [Transfer::SIGNATURE_HASH, Approval::SIGNATURE_HASH]);
+        assert_eq!(
+            filter,
+            [Transfer::SIGNATURE_HASH, Approval::SIGNATURE_HASH].into_iter().collect()
+        );
+
+        let transfer = Transfer { from: Address::ZERO, to: Address::ZERO, value: U256::ZERO };
+        let topics = encode_topics(&transfer);
+        assert!(matches_topic0(&Transfer::SIGNATURE_HASH, &topics));
+        assert!(!matches_topic0(&Approval::SIGNATURE_HASH, &topics));
+        assert!(filter.matches(&topics));
+
+        let deposit = Deposit { owner: Address::ZERO, value: U256::ZERO };
+        assert!(!filter.matches(&encode_topics(&deposit)));
+
+        assert!(!matches_topic0(&Transfer::SIGNATURE_HASH, &[]));
+        assert!(!filter.matches(&[]));
+        assert!(!EventTopicFilter::new().matches(&[Transfer::SIGNATURE_HASH]));
+
+        let topic0: Vec<B256> = filter.into();
+        assert_eq!(topic0, [Transfer::SIGNATURE_HASH, Approval::SIGNATURE_HASH]);
+    }
+}
@@ -5,6 +5,9 @@ use crate::{
 use alloc::vec::Vec;
 use alloy_primitives::{FixedBytes, Log, LogData, B256};
 
+mod filter;
+pub use filter::{matches_topic0, EventTopicFilter};
+
 mod topic;
 pub use topic::EventTopic;
 
@@ -52,6 +55,18 @@ pub trait SolEvent: Sized {
     /// Whether the event is anonymous.
     const ANONYMOUS: bool;
 
+    /// The number of topics emitted by this event, including the signature
+    /// hash (`topic0`) for non-anonymous events.
+    ///
+    /// This is always equal to `Self::TopicList::COUNT`.
+    const TOPIC_COUNT: usize = <Self::TopicList as TopicList>::COUNT;
+
+    /// Whether the non-indexed data section has a statically-known size.
+    ///
+    /// If `true`, the encoded data length is always
+    /// `<Self::DataTuple as SolType>::ENCODED_SIZE`.
+    const DATA_STATIC: bool = !<Self::DataTuple<'static> as SolType>::DYNAMIC;
+
     /// Convert decoded rust data to the event type.
     fn new(
         topics: <Self::TopicList as SolType>::RustType,
@@ -141,17 +156,61 @@ pub trait SolEvent: Sized {
         <Self::DataTuple<'a> as SolType>::abi_decode_sequence(data, validate)
     }
 
+    /// Adds context to an error returned when decoding this event from a log.
+    ///
+    /// The default implementation returns the error unchanged.
+    #[inline]
+    fn map_decode_err(error: crate::Error) -> crate::Error {
+        error
+    }
+
     /// Decode the event from the given log info.
     fn decode_raw_log<I, D>(topics: I, data: &[u8], validate: bool) -> Result<Self>
     where
         I: IntoIterator<Item = D>,
         D: Into<WordToken>,
     {
-        let topics = Self::decode_topics(topics)?;
-        let body = Self::abi_decode_data(data, validate)?;
+        let topics = Self::decode_topics(topics).map_err(Self::map_decode_err)?;
+        let body = Self::abi_decode_data(data, validate).map_err(Self::map_decode_err)?;
         Ok(Self::new(topics, body))
     }
 
+    /// Decode the event from the given log info, allowing extra data after the
+    /// event's data section, such as fields added by newer versions of the
+    /// event.
+    ///
+    /// Returns the event, and the number of trailing bytes of `data` that were
+    /// not decoded. If `validate` is true, the decoded prefix of `data` must be
+    /// canonically encoded, but the trailing bytes are not checked.
+    ///
+    /// The number of trailing bytes is computed from the length of the
+    /// canonical encoding of the decoded values. It is therefore only exact
+    /// when validating: otherwise, non-canonical data, like offsets pointing
+    /// past the canonical encoding, may have been read from the trailing bytes.
+    fn decode_raw_log_ext<I, D>(topics: I, data: &[u8], validate: bool) -> Result<(Self, usize)>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<WordToken>,
+    {
+        let topics = Self::decode_topics(topics).map_err(Self::map_decode_err)?;
+
+        let mut decoder = crate::abi::Decoder::new(data, validate);
+        let token =
+            decoder.decode_sequence::<Self::DataToken<'_>>().map_err(Self::map_decode_err)?;
+        // `total_words` includes the first dynamic offset, which is not encoded in a sequence
+        let offset = <Self::DataToken<'_> as Token>::DYNAMIC as usize * Word::len_bytes();
+        let len = (token.total_words() * Word::len_bytes()).saturating_sub(offset);
+        if validate {
+            <Self::DataTuple<'_> as SolType>::type_check(&token).map_err(Self::map_decode_err)?;
+            if data.get(..len) != Some(&crate::abi::encode_sequence(&token)[..]) {
+                return Err(Self::map_decode_err(crate::Error::ReserMismatch));
+            }
+        }
+
+        let body = <Self::DataTuple<'_> as SolType>::detokenize(token);
+        Ok((Self::new(topics, body), data.len().saturating_sub(len)))
+    }
+
     /// Decode the event from the given log object.
     fn decode_log_data(log: &LogData, validate: bool) -> Result<Self> {
         Self::decode_raw_log(log.topics(), &log.data, validate)
@@ -1,7 +1,7 @@
 use crate::{
     abi::{Token, TokenSeq},
     private::SolTypeValue,
-    Result, SolType, Word,
+    ContractError, Result, SolInterface, SolType, Word,
 };
 use alloc::vec::Vec;
 
@@ -38,6 +38,15 @@ pub trait SolCall: Sized {
     /// The function selector: `keccak256(SIGNATURE)[0..4]`
     const SELECTOR: [u8; 4];
 
+    /// Whether the function is `payable`, and can thus receive value.
+    const PAYABLE: bool = false;
+
+    /// The names of the function's return values, in order.
+    ///
+    /// These are the field names of [`Return`](Self::Return): unnamed return
+    /// values are named after their index, like `_0`.
+    const RETURN_NAMES: &'static [&'static str] = &[];
+
     /// Convert from the tuple type used for ABI encoding and decoding.
     fn new(tuple: <Self::Parameters<'_> as SolType>::RustType) -> Self;
 
@@ -73,6 +82,25 @@ pub trait SolCall: Sized {
         Self::abi_decode_raw(data, validate)
     }
 
+    /// ABI decode this call's arguments from the given slice, either **with**
+    /// or **without** the selector.
+    ///
+    /// If `data` starts with [`SELECTOR`](Self::SELECTOR), it is stripped and
+    /// the rest is decoded as the arguments. Otherwise, all of `data` is
+    /// decoded as the arguments.
+    ///
+    /// Note that this is ambiguous if the encoded arguments themselves start
+    /// with the selector bytes, for example if the first argument is a
+    /// `bytes4` equal to the selector. Such inputs are always decoded as if
+    /// they had the selector. Prefer [`abi_decode`](Self::abi_decode) or
+    /// [`abi_decode_raw`](Self::abi_decode_raw) when it is known whether the
+    /// selector is present.
+    #[inline]
+    fn abi_decode_flexible(data: &[u8], validate: bool) -> Result<Self> {
+        let data = data.strip_prefix(&Self::SELECTOR).unwrap_or(data);
+        Self::abi_decode_raw(data, validate)
+    }
+
     /// ABI encode the call to the given buffer **without** its selector.
     #[inline]
     fn abi_encode_raw(&self, out: &mut Vec<u8>) {
@@ -89,9 +117,54 @@ pub trait SolCall: Sized {
         out
     }
 
+    /// ABI encode many calls of this type into a single buffer, each **with**
+    /// its selector.
+    ///
+    /// Returns the offsets in `out` at which each encoded call starts. The
+    /// encoder's allocations are reused across all calls, which makes this
+    /// faster than calling [`abi_encode`](Self::abi_encode) on each call.
+    fn abi_encode_many(calls: &[Self], out: &mut Vec<u8>) -> Vec<usize> {
+        let mut offsets = Vec::with_capacity(calls.len());
+        let mut enc = crate::abi::Encoder::new();
+        for call in calls {
+            let token = call.tokenize();
+            enc.clear();
+            enc.append_head_tail(&token);
+
+            offsets.push(out.len());
+            out.extend_from_slice(&Self::SELECTOR);
+            out.extend_from_slice(enc.as_bytes());
+        }
+        offsets
+    }
+
     /// ABI decode this call's return values from the given slice.
     fn abi_decode_returns(data: &[u8], validate: bool) -> Result<Self::Return>;
 
+    /// ABI decode the data returned by this call, which may either be its
+    /// return values or the revert data of a [`ContractError<E>`].
+    ///
+    /// A known error selector at the start of the data is not enough to treat
+    /// it as revert data, as it may also be the start of valid return data.
+    /// Instead, the data is only decoded as an error if:
+    /// - its length is 4 more than a multiple of 32, which is never the case for ABI-encoded return
+    ///   values; and
+    /// - it is the exact, canonical encoding of one of the known errors.
+    ///
+    /// Otherwise, it is decoded as the return values with
+    /// [`abi_decode_returns`](Self::abi_decode_returns).
+    fn abi_decode_response<E: SolInterface>(
+        data: &[u8],
+        validate: bool,
+    ) -> Result<core::result::Result<Self::Return, ContractError<E>>> {
+        if data.len() % 32 == 4 {
+            if let Ok(error) = ContractError::<E>::abi_decode(data, true) {
+                return Ok(Err(error));
+            }
+        }
+        Self::abi_decode_returns(data, validate).map(Ok)
+    }
+
     /// ABI encode the call's return values.
     #[inline]
     fn abi_encode_returns<'a, E>(e: &'a E) -> Vec<u8>
@@ -84,13 +84,36 @@ pub trait SolInterface: Sized {
     }
 
     /// ABI-decodes the given data into one of the variants of `self`.
+    ///
+    /// In builds with `debug_assertions` enabled and when `validate` is
+    /// `true`, the decoded value is re-encoded and asserted to be equal to the
+    /// input. This catches bugs in the implementation early.
+    ///
+    /// Note that this only holds for *canonically* encoded inputs, as produced
+    /// by the Solidity compiler or by [`abi_encode`]. Validation already
+    /// rejects non-canonical inputs with [`Error::ReserMismatch`], and no check
+    /// is performed without validation.
+    ///
+    /// [`abi_encode`]: SolInterface::abi_encode
     #[inline]
     fn abi_decode(data: &[u8], validate: bool) -> Result<Self> {
         if data.len() < Self::MIN_DATA_LENGTH.saturating_add(4) {
             Err(crate::Error::type_check_fail(data, Self::NAME))
         } else {
-            let (selector, data) = crate::impl_core::split_array_ref(data);
-            Self::abi_decode_raw(*selector, data, validate)
+            let (selector, rest) = crate::impl_core::split_array_ref(data);
+            let decoded = Self::abi_decode_raw(*selector, rest, validate)?;
+            #[cfg(debug_assertions)]
+            if validate {
+                let encoded = decoded.abi_encode();
+                assert!(
+                    encoded == data,
+                    "{} did not round-trip: decoded {}, re-encoded {}",
+                    Self::NAME,
+                    hex::encode_prefixed(data),
+                    hex::encode_prefixed(&encoded),
+                );
+            }
+            Ok(decoded)
         }
     }
 }
@@ -1,5 +1,8 @@
 pub mod data_type;
 
+mod debug;
+pub use debug::{DebugWith, SolTypeDebug};
+
 mod r#enum;
 pub use r#enum::SolEnum;
 
@@ -7,7 +10,7 @@ mod error;
 pub use error::{decode_revert_reason, Panic, PanicKind, Revert, SolError};
 
 mod event;
-pub use event::{EventTopic, SolEvent, TopicList};
+pub use event::{matches_topic0, EventTopic, EventTopicFilter, SolEvent, TopicList};
 
 mod function;
 pub use function::{SolCall, SolConstructor};
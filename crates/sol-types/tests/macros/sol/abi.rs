@@ -545,6 +545,43 @@ mod not_contract {
     }
 }
 
+// The contract ABI contains every declared item and serializes to a JSON array
+#[test]
+fn contract_abi_items() {
+    sol! {
+        #[sol(abi)]
+        contract Token {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+            event Approval(address indexed owner, address indexed spender, uint256 value);
+            error InsufficientBalance(uint256 available, uint256 required);
+
+            constructor(string name);
+            fallback() external;
+            receive() external payable;
+
+            function transfer(address to, uint256 amount) external returns (bool);
+            function transfer(address to) external returns (bool);
+            function balanceOf(address owner) external view returns (uint256);
+        }
+    }
+
+    let abi = Token::abi::contract();
+    assert_eq!(abi.len(), 9);
+    assert!(abi.constructor.is_some());
+    assert!(abi.fallback.is_some());
+    assert!(abi.receive.is_some());
+    assert_eq!(abi.function("transfer").unwrap().len(), 2);
+    assert_eq!(abi.function("balanceOf").unwrap().len(), 1);
+    assert_eq!(abi.event("Transfer").unwrap().len(), 1);
+    assert_eq!(abi.event("Approval").unwrap().len(), 1);
+    assert_eq!(abi.error("InsufficientBalance").unwrap().len(), 1);
+
+    let json = serde_json::to_value(&abi).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), abi.len());
+    let json = serde_json::to_string(&json).unwrap();
+    assert_eq!(serde_json::from_str::<alloy_json_abi::JsonAbi>(&json).unwrap(), abi);
+}
+
 fn param(s: &str) -> Param {
     let (ty, name) = s.split_once(' ').unwrap();
     Param { ty: ty.into(), name: name.into(), internal_type: None, components: vec![] }
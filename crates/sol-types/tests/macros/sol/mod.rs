@@ -1,4 +1,4 @@
-use alloy_primitives::{b256, hex, keccak256, Address, I256, U256};
+use alloy_primitives::{address, b256, hex, keccak256, Address, B256, I256, U256};
 use alloy_sol_types::{sol, SolCall, SolError, SolEvent, SolStruct, SolType};
 use serde::Serialize;
 use serde_json::Value;
@@ -8,6 +8,9 @@ mod abi;
 #[cfg(feature = "json")]
 mod json;
 
+#[cfg(feature = "rlp")]
+mod rlp;
+
 mod eip712;
 
 #[test]
@@ -190,6 +193,60 @@ fn empty_call() {
     let depositCall {} = depositCall::abi_decode_raw(&[], true).unwrap();
 }
 
+// Errors without parameters are encoded as just their selector
+#[test]
+fn empty_error() {
+    sol! {
+        #[derive(Debug, PartialEq)]
+        interface Auth {
+            error Unauthorized();
+        }
+    }
+    use alloy_sol_types::SolInterface;
+    use Auth::{AuthErrors, Unauthorized};
+
+    assert_eq!(Unauthorized::SIGNATURE, "Unauthorized()");
+    assert_eq!(Unauthorized::SELECTOR, keccak256("Unauthorized()")[..4]);
+    assert_eq!(Unauthorized {}.abi_encode(), Unauthorized::SELECTOR);
+    assert_eq!(Unauthorized {}.abi_encoded_size(), 0);
+
+    let returndata = Unauthorized::SELECTOR;
+    let Unauthorized {} = Unauthorized::abi_decode(&returndata, true).unwrap();
+    let Unauthorized {} = Unauthorized::abi_decode_raw(&[], true).unwrap();
+    assert_eq!(
+        AuthErrors::abi_decode(&returndata, true).unwrap(),
+        AuthErrors::Unauthorized(Unauthorized {})
+    );
+    assert!(Unauthorized::abi_decode(&returndata[..3], true).is_err());
+}
+
+#[test]
+fn encode_many() {
+    sol! {
+        function transfer(address to, uint256 amount, bytes data);
+    }
+
+    let calls: Vec<_> = (0..4u8)
+        .map(|i| transferCall {
+            to: Address::with_last_byte(i),
+            amount: U256::from(i),
+            data: vec![i; i as usize],
+        })
+        .collect();
+
+    let mut out = vec![0xff];
+    let offsets = transferCall::abi_encode_many(&calls, &mut out);
+    let mut expected_offsets = vec![];
+    let mut expected = vec![0xff];
+    for call in &calls {
+        expected_offsets.push(expected.len());
+        expected.extend(call.abi_encode());
+    }
+    assert_eq!(offsets, expected_offsets);
+    assert_eq!(out, expected);
+    assert!(transferCall::abi_encode_many(&[], &mut out).is_empty());
+}
+
 #[test]
 fn function_names() {
     sol! {
@@ -558,6 +615,137 @@ fn raw_identifiers() {
     assert_eq!(tryCall::SIGNATURE, "try()");
 }
 
+#[test]
+fn debug_format() {
+    sol! {
+        type Price is uint256;
+
+        enum Side {
+            Buy,
+            Sell,
+        }
+
+        struct Order {
+            address maker;
+            uint256 amount;
+            int8 delta;
+            bytes data;
+            bytes4 tag;
+            string name;
+            Side side;
+            Price[] prices;
+        }
+
+        event Filled(address indexed maker, string indexed name, Order order);
+        function fill(Order order) returns (bool);
+    }
+
+    let order = Order {
+        maker: address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"),
+        amount: U256::from(1_000_000),
+        delta: -1,
+        data: vec![0xde, 0xad],
+        tag: [0xbe, 0xef, 0x00, 0x01].into(),
+        name: "alice".into(),
+        side: Side::Sell,
+        prices: vec![U256::from(10)],
+    };
+    assert_eq!(
+        format!("{order:?}"),
+        "Order { maker: 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045, amount: 1000000, delta: -1, \
+         data: 0xdead, tag: 0xbeef0001, name: \"alice\", side: Sell, prices: [10] }"
+    );
+    assert_eq!(format!("{:?}", Price::from(U256::from(5))), "Price(5)");
+    assert_eq!(format!("{:?}", fillReturn { _0: true }), "fillReturn { _0: true }");
+
+    let filled = Filled { maker: Address::ZERO, name: B256::ZERO, order: order.clone() };
+    assert!(format!("{filled:?}").starts_with(
+        "Filled { maker: 0x0000000000000000000000000000000000000000, \
+         name: 0x0000000000000000000000000000000000000000000000000000000000000000, order: Order {"
+    ));
+}
+
+#[test]
+fn debug_format_derives() {
+    sol! {
+        #![sol(all_derives)]
+
+        #[derive(Debug)]
+        struct Derived {
+            address a;
+        }
+
+        #[cfg_attr(all(), derive(Debug, PartialOrd))]
+        struct CfgDerived {
+            uint256 b;
+        }
+
+        #[sol(debug = false)]
+        struct Manual {
+            bytes c;
+        }
+
+        #[derive(Debug)]
+        #[sol(debug = false)]
+        struct KeepDerived {
+            bytes4 d;
+        }
+
+        struct AllDerives {
+            address e;
+        }
+    }
+
+    impl std::fmt::Debug for Manual {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("manual")
+        }
+    }
+
+    let a = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    assert_eq!(
+        format!("{:?}", Derived { a }),
+        "Derived { a: 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045 }"
+    );
+    let b = CfgDerived { b: U256::from(10) };
+    assert!(b < CfgDerived { b: U256::from(11) });
+    assert_eq!(format!("{b:?}"), "CfgDerived { b: 10 }");
+    assert_eq!(format!("{:?}", Manual { c: vec![1] }), "manual");
+    assert_eq!(
+        format!("{:?}", KeepDerived { d: [1, 2, 3, 4].into() }),
+        "KeepDerived { d: 0x01020304 }"
+    );
+    assert_eq!(
+        format!("{:?}", AllDerives { e: a }),
+        "AllDerives { e: 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045 }"
+    );
+}
+
+// Keywords in both Rust and Solidity are not valid Solidity identifiers, but they can still be
+// used as parameter names in JSON ABIs.
+#[test]
+fn rust_and_solidity_keywords() {
+    sol! {
+        struct Order {
+            uint8 type;
+            bool override;
+        }
+
+        function setType(uint8 type, Order order) returns (bool match);
+    }
+
+    let order = Order { r#type: 1, r#override: true };
+    let call = setTypeCall { r#type: 2, order };
+    assert_eq!(setTypeCall::SIGNATURE, "setType(uint8,(uint8,bool))");
+    assert_eq!(Order::NAME, "Order");
+    assert_eq!(Order::eip712_encode_type(), "Order(uint8 type,bool override)");
+    let decoded = setTypeCall::abi_decode(&call.abi_encode(), true).unwrap();
+    assert_eq!(decoded.r#type, 2);
+    assert_eq!(decoded.order.r#type, 1);
+    assert!(decoded.order.r#override);
+    let _ = setTypeReturn { r#match: true };
+}
+
 // Translate contract types to `address`
 // https://github.com/alloy-rs/core/issues/347
 #[test]
@@ -776,3 +964,1166 @@ fn decoder_fixed_array_before_dynamic() {
     let decoded = FullReport::abi_decode(&encoded, true).unwrap();
     assert_eq!(decoded, full_report);
 }
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn event_topic_count_and_data_static() {
+    sol! {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Memo(address indexed from, uint256 indexed id, string memo);
+        event Anon(address indexed from, uint256 indexed id, uint256 value) anonymous;
+    }
+
+    assert_eq!(Transfer::TOPIC_COUNT, 3);
+    assert!(Transfer::DATA_STATIC);
+
+    assert_eq!(Memo::TOPIC_COUNT, 3);
+    assert!(!Memo::DATA_STATIC);
+
+    assert_eq!(Anon::TOPIC_COUNT, 2);
+    assert!(Anon::DATA_STATIC);
+}
+
+// Indexed structs, tuples and arrays are hashed, even if they are static, while
+// value types like enums and UDVTs are stored directly
+#[test]
+fn indexed_struct_event() {
+    sol! {
+        struct Point {
+            uint256 x;
+            address y;
+        }
+
+        enum Side { Buy, Sell }
+        type Price is uint128;
+
+        event Moved(Point indexed point, (bool, uint8) indexed pair, uint256[2] indexed arr);
+        event Traded(Side indexed side, Price indexed price);
+    }
+
+    let point = Point { x: U256::from(1), y: Address::repeat_byte(0x11) };
+    let point_hash = keccak256(Point::abi_encode(&point));
+    let pair_hash = keccak256(<sol!((bool, uint8))>::abi_encode(&(true, 2)));
+    let arr_hash = keccak256(<sol!(uint256[2])>::abi_encode(&[U256::from(3), U256::from(4)]));
+    assert_eq!(<Point as alloy_sol_types::EventTopic>::encode_topic(&point).0, point_hash);
+
+    let moved = Moved { point: point_hash, pair: pair_hash, arr: arr_hash };
+    let topics = moved.encode_topics();
+    assert_eq!(topics.len(), 4);
+    assert_eq!(topics[0].0, Moved::SIGNATURE_HASH);
+    assert_eq!(topics[1].0, point_hash);
+    assert_eq!(topics[2].0, pair_hash);
+    assert_eq!(topics[3].0, arr_hash);
+
+    let decoded = Moved::decode_raw_log(topics.iter().map(|t| t.0), &[], true).unwrap();
+    assert_eq!(decoded.point, point_hash);
+    assert_eq!(decoded.pair, pair_hash);
+    assert_eq!(decoded.arr, arr_hash);
+
+    let traded = Traded { side: Side::Sell, price: 5 };
+    let topics = traded.encode_topics();
+    assert_eq!(topics[1].0, B256::with_last_byte(1));
+    assert_eq!(topics[2].0, B256::with_last_byte(5));
+
+    let decoded = Traded::decode_raw_log(topics.iter().map(|t| t.0), &[], true).unwrap();
+    assert!(matches!(decoded.side, Side::Sell));
+    assert_eq!(decoded.price, 5);
+}
+
+// `SolInterface::abi_decode` re-encodes canonical inputs in debug builds
+#[test]
+fn interface_decode_roundtrip() {
+    sol! {
+        interface Store {
+            function set(bytes data, uint256 index, string[] names);
+            function get(uint256 index) returns (bytes);
+        }
+    }
+    use alloy_sol_types::SolInterface;
+    use Store::*;
+
+    let call = StoreCalls::set(setCall {
+        data: vec![1, 2, 3],
+        index: U256::from(42),
+        names: vec!["a".into(), "bc".into()],
+    });
+    let encoded = call.abi_encode();
+    let decoded = StoreCalls::abi_decode(&encoded, true).unwrap();
+    assert_eq!(decoded.abi_encode(), encoded);
+
+    // non-canonical inputs are rejected, instead of failing the debug assertion
+    let mut trailing = encoded.clone();
+    trailing.extend([0u8; 32]);
+    assert!(StoreCalls::abi_decode(&trailing, false).is_ok());
+    assert!(matches!(
+        StoreCalls::abi_decode(&trailing, true),
+        Err(alloy_sol_types::Error::DecodeFailed { type_name: "setCall", source })
+            if matches!(*source, alloy_sol_types::Error::ReserMismatch)
+    ));
+
+    let call = StoreCalls::get(getCall { index: U256::from(1) });
+    assert_eq!(
+        StoreCalls::abi_decode(&call.abi_encode(), true).unwrap().abi_encode(),
+        call.abi_encode()
+    );
+}
+
+// Non-canonical inputs can be decoded without validation
+#[test]
+fn interface_decode_non_canonical() {
+    sol! {
+        interface Store {
+            function set(bytes data, uint256 index);
+        }
+    }
+    use alloy_sol_types::SolInterface;
+    use Store::*;
+
+    // `data` is placed one word further than its canonical offset
+    let mut encoded = setCall::SELECTOR.to_vec();
+    encoded.extend(hex!(
+        "
+        0000000000000000000000000000000000000000000000000000000000000060
+        000000000000000000000000000000000000000000000000000000000000002a
+        0000000000000000000000000000000000000000000000000000000000000000
+        0000000000000000000000000000000000000000000000000000000000000001
+        0100000000000000000000000000000000000000000000000000000000000000
+        "
+    ));
+    let decoded = StoreCalls::abi_decode(&encoded, false).unwrap();
+    let StoreCalls::set(set) = &decoded;
+    assert_eq!(set.data, [1]);
+    assert_eq!(set.index, U256::from(42));
+
+    assert!(StoreCalls::abi_decode(&encoded, true).is_err());
+}
+
+// Interfaces generate the same bindings as contracts
+#[test]
+fn interface_contract_parity() {
+    sol! {
+        interface IVault is IERC20 {
+            event Deposit(address indexed owner, uint256 amount);
+            error Insufficient(uint256 available, uint256 required);
+
+            function deposit(uint256 amount) external returns (bool);
+            function withdraw(uint256 amount, address to) external;
+        }
+    }
+    sol! {
+        contract Vault {
+            event Deposit(address indexed owner, uint256 amount);
+            error Insufficient(uint256 available, uint256 required);
+
+            function deposit(uint256 amount) external returns (bool) {}
+            function withdraw(uint256 amount, address to) external {}
+        }
+    }
+    use alloy_sol_types::{SolEventInterface, SolInterface};
+
+    assert_eq!(IVault::depositCall::SIGNATURE, Vault::depositCall::SIGNATURE);
+    assert_eq!(IVault::withdrawCall::SELECTOR, Vault::withdrawCall::SELECTOR);
+    assert_eq!(IVault::Deposit::SIGNATURE_HASH, Vault::Deposit::SIGNATURE_HASH);
+    assert_eq!(IVault::Insufficient::SELECTOR, Vault::Insufficient::SELECTOR);
+    assert_eq!(IVault::IVaultCalls::COUNT, Vault::VaultCalls::COUNT);
+    assert_eq!(
+        IVault::IVaultCalls::selectors().collect::<Vec<_>>(),
+        Vault::VaultCalls::selectors().collect::<Vec<_>>()
+    );
+
+    let call = IVault::withdrawCall { amount: U256::from(1), to: Address::ZERO };
+    let encoded = IVault::IVaultCalls::withdraw(call.clone()).abi_encode();
+    assert_eq!(
+        encoded,
+        Vault::VaultCalls::withdraw(Vault::withdrawCall {
+            amount: U256::from(1),
+            to: Address::ZERO
+        })
+        .abi_encode()
+    );
+    let IVault::IVaultCalls::withdraw(decoded) =
+        IVault::IVaultCalls::abi_decode(&encoded, true).unwrap()
+    else {
+        panic!("wrong variant");
+    };
+    assert_eq!(decoded.amount, call.amount);
+    assert_eq!(decoded.to, call.to);
+
+    let error = IVault::Insufficient { available: U256::from(1), required: U256::from(2) };
+    let encoded = IVault::IVaultErrors::Insufficient(error).abi_encode();
+    assert!(matches!(
+        IVault::IVaultErrors::abi_decode(&encoded, true),
+        Ok(IVault::IVaultErrors::Insufficient(_))
+    ));
+
+    let event = IVault::Deposit { owner: Address::ZERO, amount: U256::from(3) };
+    let log = alloy_primitives::Log::new_unchecked(
+        Address::ZERO,
+        event.encode_topics().into_iter().map(|t| t.0).collect(),
+        event.encode_data().into(),
+    );
+    assert!(matches!(
+        IVault::IVaultEvents::decode_log(&log, true).map(|log| log.data),
+        Ok(IVault::IVaultEvents::Deposit(_))
+    ));
+}
+
+// Anonymous nested tuples in return values map to nested Rust tuples
+#[test]
+fn nested_tuple_returns() {
+    sol! {
+        function nested() returns ((uint256, address), bytes data);
+    }
+
+    let addr = Address::repeat_byte(0x11);
+    let encoded = hex!(
+        "
+        0000000000000000000000000000000000000000000000000000000000000001
+        0000000000000000000000001111111111111111111111111111111111111111
+        0000000000000000000000000000000000000000000000000000000000000060
+        0000000000000000000000000000000000000000000000000000000000000002
+        abcd000000000000000000000000000000000000000000000000000000000000
+        "
+    );
+
+    let ret = nestedCall::abi_decode_returns(&encoded, true).unwrap();
+    let (inner, data): ((U256, Address), Vec<u8>) = (ret._0, ret.data);
+    assert_eq!(inner, (U256::from(1), addr));
+    assert_eq!(data, hex!("abcd"));
+
+    let tuple = <nestedCall as SolCall>::ReturnTuple::<'_>::abi_encode_sequence(&(inner, data));
+    assert_eq!(tuple, encoded);
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn payable_calls() {
+    sol! {
+        contract C {
+            function deposit() external payable;
+            function withdraw(uint256 amount) external;
+            function balance() external view returns (uint256);
+        }
+    }
+
+    assert!(C::depositCall::PAYABLE);
+    assert!(!C::withdrawCall::PAYABLE);
+    assert!(!C::balanceCall::PAYABLE);
+}
+
+// Non-empty return data for a function without return values is probably revert data
+#[test]
+fn void_returns_unexpected_data() {
+    sol! {
+        function ping() external view;
+    }
+
+    assert!(pingCall::abi_decode_returns(&[], true).is_ok());
+    assert!(pingCall::abi_decode_returns(&[], false).is_ok());
+
+    let revert = alloy_sol_types::Revert::from("not allowed").abi_encode();
+    for validate in [true, false] {
+        let err = pingCall::abi_decode_returns(&revert, validate).unwrap_err();
+        assert!(
+            matches!(&err, alloy_sol_types::Error::UnexpectedReturnData(data) if data[..] == revert[..])
+        );
+        assert_eq!(
+            err.to_string(),
+            "unexpected return data for function without return values, probably reverted: \
+             revert: not allowed"
+        );
+    }
+
+    let err = pingCall::abi_decode_returns(&[0xff, 0xff], true).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected return data for function without return values: 0xffff"
+    );
+}
+
+#[test]
+fn call_new_args() {
+    sol! {
+        function transfer(address to, uint256 amount, bytes);
+        function ping();
+    }
+
+    let to = Address::repeat_byte(0x11);
+    let call = transferCall::new_args(to, U256::from(100), vec![0xab]);
+    assert_eq!(call.to, to);
+    assert_eq!(call.amount, U256::from(100));
+    assert_eq!(call._2, [0xab]);
+    assert_eq!(
+        call.abi_encode(),
+        transferCall::new((to, U256::from(100), vec![0xab])).abi_encode()
+    );
+
+    let _ = pingCall::new_args();
+}
+
+#[test]
+fn max_bytes() {
+    sol! {
+        function submit(#[sol(max_bytes = 4)] bytes data, #[sol(max_bytes = 2)] string memo, bytes rest);
+    }
+
+    let call = submitCall { data: vec![1, 2, 3, 4], memo: "ab".into(), rest: vec![0; 100] };
+    let encoded = call.abi_encode();
+    let decoded = submitCall::abi_decode(&encoded, true).unwrap();
+    assert_eq!(decoded.data, call.data);
+    assert_eq!(decoded.memo, call.memo);
+
+    let call = submitCall { data: vec![0; 5], memo: "ab".into(), rest: vec![] };
+    let err = submitCall::abi_decode(&call.abi_encode(), false).unwrap_err();
+    assert!(matches!(
+        err,
+        alloy_sol_types::Error::DecodeFailed { type_name: "submitCall", ref source }
+            if matches!(**source, alloy_sol_types::Error::LimitExceeded { name: "data", limit: 4, len: 5 })
+    ));
+    assert_eq!(
+        err.to_string(),
+        "could not decode submitCall: `data` exceeds the maximum length of 4 bytes: 5"
+    );
+
+    let call = submitCall { data: vec![], memo: "abc".into(), rest: vec![] };
+    let err = submitCall::abi_decode(&call.abi_encode(), true).unwrap_err();
+    assert!(matches!(
+        err,
+        alloy_sol_types::Error::DecodeFailed { type_name: "submitCall", source }
+            if matches!(*source, alloy_sol_types::Error::LimitExceeded { name: "memo", limit: 2, len: 3 })
+    ));
+
+    // the limit applies to the encoded length, and not to the lossily decoded string
+    let mut encoded = submitCall { data: vec![], memo: "ab".into(), rest: vec![] }.abi_encode();
+    let memo = encoded.windows(2).rposition(|w| w == b"ab").unwrap();
+    encoded[memo..memo + 2].copy_from_slice(&[0xff, 0xff]);
+    let decoded = submitCall::abi_decode(&encoded, false).unwrap();
+    assert_eq!(decoded.memo, "\u{fffd}\u{fffd}");
+    assert!(submitCall::abi_decode(&encoded, true).is_err());
+}
+
+#[test]
+fn max_bytes_struct() {
+    sol! {
+        #[derive(PartialEq)]
+        struct Payload {
+            uint256 id;
+            #[sol(max_bytes = 4)]
+            bytes data;
+        }
+
+        function submitPayload(Payload payload);
+    }
+
+    let payload = Payload { id: U256::from(1), data: vec![1, 2, 3, 4] };
+    assert_eq!(Payload::abi_decode(&Payload::abi_encode(&payload), true).unwrap(), payload);
+
+    let payload = Payload { id: U256::from(1), data: vec![0; 5] };
+    let limit_exceeded = |err: alloy_sol_types::Error| {
+        matches!(
+            err,
+            alloy_sol_types::Error::DecodeFailed { type_name: "Payload", ref source }
+                if matches!(**source, alloy_sol_types::Error::LimitExceeded { name: "data", limit: 4, len: 5 })
+        )
+    };
+    let encoded = Payload::abi_encode(&payload);
+    let encoded_params = Payload::abi_encode_params(&payload);
+    for validate in [false, true] {
+        assert!(limit_exceeded(Payload::abi_decode(&encoded, validate).unwrap_err()));
+        assert!(limit_exceeded(Payload::abi_decode_params(&encoded_params, validate).unwrap_err()));
+        assert!(limit_exceeded(
+            Payload::abi_decode_sequence(&encoded_params, validate).unwrap_err()
+        ));
+    }
+
+    // nested inside of another type, the limit is checked when validating
+    let call = submitPayloadCall { payload: payload.clone() };
+    let encoded = call.abi_encode();
+    assert_eq!(submitPayloadCall::abi_decode(&encoded, false).unwrap().payload, payload);
+    let err = submitPayloadCall::abi_decode(&encoded, true).unwrap_err();
+    assert!(matches!(
+        err,
+        alloy_sol_types::Error::DecodeFailed { type_name: "submitPayloadCall", source }
+            if matches!(*source, alloy_sol_types::Error::LimitExceeded { name: "data", limit: 4, len: 5 })
+    ));
+}
+
+#[test]
+fn deploy_builder() {
+    sol! {
+        #[sol(bytecode = "0x6080604052", deployed_bytecode = "0x60806040")]
+        contract Token {
+            constructor(string name, uint256) {}
+        }
+
+        #[sol(bytecode = "0x60016002")]
+        contract Empty {}
+    }
+
+    assert_eq!(Token::BYTECODE[..], hex!("6080604052"));
+    assert_eq!(Token::DEPLOYED_BYTECODE[..], hex!("60806040"));
+
+    let code = Token::deploy_builder("A".into(), U256::from(18));
+    let args = Token::constructorCall { name: "A".into(), _1: U256::from(18) };
+    assert_eq!(code[..5], Token::BYTECODE[..]);
+    assert_eq!(code[5..], alloy_sol_types::SolConstructor::abi_encode(&args)[..]);
+    assert_eq!(code.len(), 5 + 32 * 4);
+
+    assert_eq!(Empty::deploy_builder(), hex!("60016002"));
+}
+
+#[test]
+fn fixed_bytes_as_array() {
+    mod arrays {
+        alloy_sol_types::sol! {
+            #![sol(fixed_bytes_as_array)]
+
+            struct Selector {
+                bytes4 selector;
+                bytes4[] others;
+                uint256 value;
+            }
+        }
+    }
+
+    mod fixed_bytes {
+        alloy_sol_types::sol! {
+            struct Selector {
+                bytes4 selector;
+                bytes4[] others;
+                uint256 value;
+            }
+        }
+    }
+
+    let array = arrays::Selector {
+        selector: [0xde, 0xad, 0xbe, 0xef],
+        others: vec![[0x12; 4].into()],
+        value: U256::from(1),
+    };
+    let fixed = fixed_bytes::Selector {
+        selector: array.selector.into(),
+        others: array.others.clone(),
+        value: array.value,
+    };
+
+    let encoded = alloy_sol_types::SolValue::abi_encode(&array);
+    assert_eq!(encoded, alloy_sol_types::SolValue::abi_encode(&fixed));
+    assert_eq!(encoded[32..36], array.selector);
+    assert!(encoded[36..64].iter().all(|&b| b == 0));
+
+    let decoded = <arrays::Selector as SolType>::abi_decode(&encoded, true).unwrap();
+    assert_eq!(decoded.selector, array.selector);
+    assert_eq!(decoded.others, array.others);
+    assert_eq!(decoded.value, array.value);
+
+    assert_eq!(array.eip712_hash_struct(), fixed.eip712_hash_struct());
+    assert_eq!(
+        <arrays::Selector as alloy_sol_types::EventTopic>::encode_topic(&array),
+        <fixed_bytes::Selector as alloy_sol_types::EventTopic>::encode_topic(&fixed)
+    );
+    assert_eq!(format!("{array:?}"), format!("{fixed:?}"));
+}
+
+#[test]
+fn decode_response() {
+    use alloy_sol_types::{ContractError, Revert};
+
+    sol! {
+        contract Vault {
+            error Unauthorized(address caller);
+            error Paused();
+
+            function balance() external returns (uint256);
+            function withdraw() external;
+        }
+    }
+    use Vault::{balanceCall, withdrawCall, VaultErrors};
+
+    // a valid return value which starts with an error selector
+    let mut word = [0u8; 32];
+    word[..4].copy_from_slice(&Vault::Unauthorized::SELECTOR);
+    let value = U256::from_be_bytes(word);
+    let data = balanceCall::abi_encode_returns(&(value,));
+    assert_eq!(data[..4], Vault::Unauthorized::SELECTOR);
+    let ret = balanceCall::abi_decode_response::<VaultErrors>(&data, true).unwrap().unwrap();
+    assert_eq!(ret._0, value);
+
+    // actual revert data
+    let error = Vault::Unauthorized { caller: Address::repeat_byte(0x11) };
+    let data = error.abi_encode();
+    let err = balanceCall::abi_decode_response::<VaultErrors>(&data, true).unwrap().unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::CustomError(VaultErrors::Unauthorized(e)) if e.caller == error.caller
+    ));
+
+    let data = Vault::Paused {}.abi_encode();
+    let err = withdrawCall::abi_decode_response::<VaultErrors>(&data, true).unwrap().unwrap_err();
+    assert!(matches!(err, ContractError::CustomError(VaultErrors::Paused(_))));
+
+    let data = Revert::from("nope").abi_encode();
+    let err = balanceCall::abi_decode_response::<VaultErrors>(&data, true).unwrap().unwrap_err();
+    assert!(matches!(err, ContractError::Revert(r) if r.reason() == "nope"));
+
+    // known selector, but not a valid encoding of the error
+    let mut data = Vault::Unauthorized::SELECTOR.to_vec();
+    data.extend([0xff; 32]);
+    assert!(balanceCall::abi_decode_response::<VaultErrors>(&data, true).is_err());
+    assert!(withdrawCall::abi_decode_response::<VaultErrors>(&[], true).unwrap().is_ok());
+}
+
+#[test]
+fn return_abi_encode() {
+    sol! {
+        function quote(uint256 amount) returns (uint256 out, address[] path, string memo);
+        function ping();
+    }
+
+    let ret = quoteReturn {
+        out: U256::from(42),
+        path: vec![Address::repeat_byte(0x11), Address::repeat_byte(0x22)],
+        memo: "ok".into(),
+    };
+    let data = ret.abi_encode();
+    assert_eq!(data, quoteCall::abi_encode_returns(&(ret.out, ret.path.clone(), ret.memo.clone())));
+
+    let decoded = quoteCall::abi_decode_returns(&data, true).unwrap();
+    assert_eq!(decoded.out, ret.out);
+    assert_eq!(decoded.path, ret.path);
+    assert_eq!(decoded.memo, ret.memo);
+
+    assert!(pingReturn {}.abi_encode().is_empty());
+    pingCall::abi_decode_returns(&pingReturn {}.abi_encode(), true).unwrap();
+}
+
+#[test]
+fn forward_references() {
+    sol! {
+        function submit(Order order, Side side) returns (Receipt);
+        event Submitted(Order order, Price price);
+        error Rejected(Order order);
+
+        /// An order, defined after its first use.
+        struct Order {
+            address maker;
+            Fill[] fills;
+        }
+
+        struct Fill {
+            Price price;
+            uint64 amount;
+        }
+
+        struct Receipt {
+            bytes32 id;
+        }
+
+        enum Side { Buy, Sell }
+
+        type Price is uint128;
+    }
+
+    assert_eq!(submitCall::SIGNATURE, "submit((address,(uint128,uint64)[]),uint8)");
+    assert_eq!(Submitted::SIGNATURE, "Submitted((address,(uint128,uint64)[]),uint128)");
+    assert_eq!(Rejected::SIGNATURE, "Rejected((address,(uint128,uint64)[]))");
+    assert_eq!(
+        Order::eip712_encode_type(),
+        "Order(address maker,Fill[] fills)Fill(uint128 price,uint64 amount)"
+    );
+
+    let order = Order { maker: Address::ZERO, fills: vec![Fill { price: 7, amount: 1 }] };
+    let call = submitCall { order, side: Side::Sell };
+    let decoded = submitCall::abi_decode(&call.abi_encode(), true).unwrap();
+    assert_eq!(decoded.order.fills[0].price, 7);
+
+    sol! {
+        contract Book {
+            function best() external returns (Level memory);
+
+            struct Level {
+                uint256 price;
+                uint256 size;
+            }
+        }
+    }
+    assert_eq!(Book::bestCall::SIGNATURE, "best()");
+    assert_eq!(<Book::Level as SolType>::sol_type_name(), "Level");
+}
+
+#[test]
+fn decode_error_names_type() {
+    sol! {
+        struct Point {
+            uint64 x;
+            uint64 y;
+        }
+
+        struct Line {
+            Point from;
+            Point to;
+        }
+
+        event Transfer(address indexed from, uint256 value);
+        error Insufficient(uint256 needed);
+        function transfer(address to, uint256 amount) returns (bool);
+    }
+
+    let err = transferCall::abi_decode_raw(&[0; 32], true).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "could not decode transferCall: buffer overrun while deserializing"
+    );
+
+    let err = transferCall::abi_decode_returns(&[0; 4], true).unwrap_err();
+    assert!(err.to_string().starts_with("could not decode transferReturn: "), "{err}");
+
+    let err = Insufficient::abi_decode_raw(&[], true).unwrap_err();
+    assert!(matches!(
+        err,
+        alloy_sol_types::Error::DecodeFailed { type_name: "Insufficient", source }
+            if matches!(*source, alloy_sol_types::Error::Overrun)
+    ));
+
+    let topics = [Transfer::SIGNATURE_HASH, B256::ZERO];
+    let err = Transfer::decode_raw_log(topics, &[0; 16], true).unwrap_err();
+    assert!(err.to_string().starts_with("could not decode Transfer: "), "{err}");
+
+    let err = Transfer::decode_raw_log([Transfer::SIGNATURE_HASH], &[0; 32], true).unwrap_err();
+    assert!(err.to_string().starts_with("could not decode Transfer: "), "{err}");
+
+    let err = <Point as SolType>::abi_decode(&[0; 32], true).unwrap_err();
+    assert!(err.to_string().starts_with("could not decode Point: "), "{err}");
+    let err = <Point as SolType>::abi_decode_params(&[0; 32], true).unwrap_err();
+    assert!(err.to_string().starts_with("could not decode Point: "), "{err}");
+    let err = <Point as SolType>::abi_decode_sequence(&[0; 32], true).unwrap_err();
+    assert!(err.to_string().starts_with("could not decode Point: "), "{err}");
+
+    // only the outermost type is named
+    let err = <Line as SolType>::abi_decode_sequence(&[0; 96], true).unwrap_err();
+    assert!(err.to_string().starts_with("could not decode Line: buffer overrun"), "{err}");
+}
+
+// Items are namespaced per contract, and overloads are resolved separately for each contract
+#[test]
+fn contracts_share_item_names() {
+    sol! {
+        contract A {
+            error InsufficientBalance(uint256 needed);
+            event Moved(uint256 amount);
+            function move(uint256 amount) external;
+        }
+
+        contract B {
+            error InsufficientBalance(uint256 needed);
+            event Moved(uint256 amount);
+            function move(uint256 amount) external;
+            function move(address to) external;
+        }
+    }
+
+    assert_eq!(A::InsufficientBalance::SIGNATURE, B::InsufficientBalance::SIGNATURE);
+    assert_eq!(A::Moved::SIGNATURE_HASH, B::Moved::SIGNATURE_HASH);
+    assert_eq!(A::moveCall::SIGNATURE, "move(uint256)");
+    assert_eq!(B::move_0Call::SIGNATURE, "move(uint256)");
+    assert_eq!(B::move_1Call::SIGNATURE, "move(address)");
+
+    let error = A::InsufficientBalance { needed: U256::from(1) };
+    let decoded =
+        <B::BErrors as alloy_sol_types::SolInterface>::abi_decode(&error.abi_encode(), true)
+            .unwrap();
+    assert!(matches!(decoded, B::BErrors::InsufficientBalance(e) if e.needed == U256::from(1)));
+}
+
+// Single one-word return values are decoded without the sequence decoder
+#[test]
+fn single_word_return() {
+    sol! {
+        enum Kind { A, B }
+        type Price is uint128;
+
+        function balanceOf(address owner) returns (uint256);
+        function small() returns (uint8);
+        function kind() returns (Kind);
+        function price() returns (Price);
+    }
+
+    let word = U256::from(42).to_be_bytes::<32>();
+    assert_eq!(balanceOfCall::abi_decode_returns(&word, true).unwrap()._0, U256::from(42));
+    assert_eq!(smallCall::abi_decode_returns(&word, true).unwrap()._0, 42);
+    assert_eq!(priceCall::abi_decode_returns(&word, true).unwrap()._0, 42);
+    let one = U256::from(1).to_be_bytes::<32>();
+    assert!(matches!(kindCall::abi_decode_returns(&one, true).unwrap()._0, Kind::B));
+
+    // same errors as decoding the sequence
+    let source = |e: alloy_sol_types::Error| match e {
+        alloy_sol_types::Error::DecodeFailed { type_name: _, source } => *source,
+        e => panic!("unexpected error: {e}"),
+    };
+    assert_eq!(
+        source(balanceOfCall::abi_decode_returns(&word[..31], false).unwrap_err()),
+        alloy_sol_types::Error::Overrun
+    );
+
+    let mut trailing = word.to_vec();
+    trailing.push(0);
+    assert!(balanceOfCall::abi_decode_returns(&trailing, false).is_ok());
+    assert_eq!(
+        source(balanceOfCall::abi_decode_returns(&trailing, true).unwrap_err()),
+        alloy_sol_types::Error::ReserMismatch
+    );
+
+    let dirty = U256::from(256).to_be_bytes::<32>();
+    assert_eq!(smallCall::abi_decode_returns(&dirty, false).unwrap()._0, 0);
+    assert_eq!(
+        source(smallCall::abi_decode_returns(&dirty, true).unwrap_err()),
+        <(alloy_sol_types::sol_data::Uint<8>,)>::abi_decode_sequence(&dirty, true).unwrap_err()
+    );
+    assert!(kindCall::abi_decode_returns(&U256::from(2).to_be_bytes::<32>(), true).is_err());
+}
+
+#[test]
+fn event_decode_trailing_data() {
+    sol! {
+        event Static(address indexed from, uint256 amount, bool flag);
+        event Dynamic(address indexed from, uint256 amount, string memo);
+    }
+
+    let from = Address::repeat_byte(0x11);
+    let extra = [0x42u8; 64];
+
+    let event = Static { from, amount: U256::from(1), flag: true };
+    let topics = event.encode_topics();
+    let mut data = event.encode_data();
+    data.extend(extra);
+    let (decoded, rest) = Static::decode_raw_log_ext(topics.iter().copied(), &data, true).unwrap();
+    assert_eq!((decoded.from, decoded.amount, decoded.flag), (from, U256::from(1), true));
+    assert_eq!(rest, 64);
+    assert!(Static::decode_raw_log(topics.iter().copied(), &data, true).is_err());
+
+    let event = Dynamic { from, amount: U256::from(2), memo: "hello".into() };
+    let topics = event.encode_topics();
+    let encoded = event.encode_data();
+    let (_, rest) = Dynamic::decode_raw_log_ext(topics.iter().copied(), &encoded, true).unwrap();
+    assert_eq!(rest, 0);
+
+    let mut data = encoded.clone();
+    data.extend(extra);
+    for validate in [false, true] {
+        let (decoded, rest) =
+            Dynamic::decode_raw_log_ext(topics.iter().copied(), &data, validate).unwrap();
+        assert_eq!(decoded.memo, "hello");
+        assert_eq!(rest, 64);
+    }
+
+    // the decoded prefix must still be canonical when validating
+    let mut data = encoded;
+    data[127] = 1;
+    assert!(Dynamic::decode_raw_log_ext(topics.iter().copied(), &data, false).is_ok());
+    let err = Dynamic::decode_raw_log_ext(topics.iter().copied(), &data, true).unwrap_err();
+    assert!(matches!(
+        err,
+        alloy_sol_types::Error::DecodeFailed { type_name: "Dynamic", source }
+            if matches!(*source, alloy_sol_types::Error::ReserMismatch)
+    ));
+
+    // errors name the event, like `decode_raw_log`
+    let err = Dynamic::decode_raw_log_ext(topics.iter().copied(), &[], true).unwrap_err();
+    assert_eq!(err, Dynamic::decode_raw_log(topics.iter().copied(), &[], true).unwrap_err());
+    assert!(err.to_string().starts_with("could not decode Dynamic: "), "{err}");
+}
+
+// `MyStruct[]` resolves to `Vec<MyStruct>` in all positions
+#[test]
+fn struct_array_positions() {
+    sol! {
+        contract Store {
+            #[derive(PartialEq)]
+            struct Item {
+                uint256 id;
+                string name;
+            }
+
+            #[derive(PartialEq)]
+            struct Inventory {
+                Item[] items;
+                Item[2] pair;
+            }
+
+            mapping(uint256 => Item[]) public byOwner;
+            Item[] public all;
+
+            function set(Item[] items, Item[2] pair) returns (Item[] previous, Inventory inventory);
+        }
+    }
+    use Store::*;
+
+    let items = vec![
+        Item { id: U256::from(1), name: "a".into() },
+        Item { id: U256::from(2), name: "bc".into() },
+    ];
+    let pair = [items[1].clone(), items[0].clone()];
+
+    let inventory = Inventory { items: items.clone(), pair: pair.clone() };
+    let encoded = <Inventory as SolType>::abi_encode(&inventory);
+    assert_eq!(<Inventory as SolType>::abi_decode(&encoded, true).unwrap(), inventory);
+    assert_eq!(encoded, <sol! { (Item[], Item[2]) }>::abi_encode(&(items.clone(), pair.clone())));
+
+    let call = setCall { items: items.clone(), pair: pair.clone() };
+    let decoded = setCall::abi_decode(&call.abi_encode(), true).unwrap();
+    assert_eq!(decoded.items, items);
+    assert_eq!(decoded.pair, pair);
+    assert_eq!(
+        call.abi_encode()[4..],
+        <sol! { (Item[], Item[2]) }>::abi_encode_params(&(items.clone(), pair.clone()))
+    );
+
+    let ret = setReturn { previous: items.clone(), inventory: inventory.clone() };
+    let decoded = setCall::abi_decode_returns(&ret.abi_encode(), true).unwrap();
+    assert_eq!(decoded.previous, items);
+    assert_eq!(decoded.inventory, inventory);
+
+    // getters of arrays take the index as an extra argument and return the element
+    assert_eq!(byOwnerCall::SIGNATURE, "byOwner(uint256,uint256)");
+    assert_eq!(allCall::SIGNATURE, "all(uint256)");
+    let ret = byOwnerReturn { _0: items[1].clone() };
+    assert_eq!(byOwnerCall::abi_decode_returns(&ret.abi_encode(), true).unwrap()._0, items[1]);
+    let ret = allReturn { _0: items[0].clone() };
+    assert_eq!(allCall::abi_decode_returns(&ret.abi_encode(), true).unwrap()._0, items[0]);
+}
+
+// Integers that don't fit in the Solidity type are not silently truncated
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic = "value out of range for uint24: 16777216"]
+fn encode_out_of_range_int() {
+    sol! {
+        struct Fee {
+            address pool;
+            uint24 fee;
+        }
+    }
+
+    let fee = Fee { pool: Address::ZERO, fee: 3000 };
+    let encoded = <Fee as SolType>::abi_encode(&fee);
+    assert_eq!(<Fee as SolType>::abi_decode(&encoded, true).unwrap().fee, 3000);
+
+    <Fee as SolType>::abi_encode(&Fee { pool: Address::ZERO, fee: 1 << 24 });
+}
+
+#[test]
+fn copy() {
+    sol! {
+        enum Side { Buy, Sell }
+        type Price is uint128;
+
+        #[sol(copy)]
+        struct Pair {
+            address token0;
+            address token1;
+        }
+
+        #[sol(copy)]
+        struct Order {
+            Pair pair;
+            Side side;
+            uint24 fee;
+            Price price;
+            bytes32[2] hashes;
+            (bool, int8) flags;
+        }
+
+        struct Batch {
+            Order[] orders;
+        }
+
+        #[sol(copy)]
+        function place(Order order, uint256 amount) returns (bytes32 id);
+    }
+
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<Price>();
+    assert_copy::<Pair>();
+    assert_copy::<Order>();
+    assert_copy::<placeCall>();
+    assert_copy::<placeReturn>();
+
+    let order = Order {
+        pair: Pair { token0: Address::ZERO, token1: Address::repeat_byte(1) },
+        side: Side::Sell,
+        fee: 3000,
+        price: 100,
+        hashes: [B256::ZERO; 2],
+        flags: (true, -1),
+    };
+    let call = placeCall { order, amount: U256::from(1) };
+    let copied = call;
+    assert_eq!(call.abi_encode(), copied.abi_encode());
+
+    let batch = Batch { orders: vec![order, order] };
+    let encoded = <Batch as SolType>::abi_encode(&batch);
+    assert_eq!(<Batch as SolType>::abi_decode(&encoded, true).unwrap().orders.len(), 2);
+}
+
+#[test]
+fn decode_flexible() {
+    sol! {
+        function transfer(address to, uint256 amount) returns (bool);
+        function register(bytes4 selector, string name);
+        function ping();
+    }
+
+    let call = transferCall { to: Address::repeat_byte(0x11), amount: U256::from(100) };
+    let with_selector = call.abi_encode();
+    let without_selector = &with_selector[4..];
+    for data in [&with_selector[..], without_selector] {
+        let decoded = transferCall::abi_decode_flexible(data, true).unwrap();
+        assert_eq!(decoded.to, call.to);
+        assert_eq!(decoded.amount, call.amount);
+    }
+    assert!(transferCall::abi_decode_flexible(&with_selector[..35], true).is_err());
+
+    assert!(pingCall::abi_decode_flexible(&pingCall::SELECTOR, true).is_ok());
+    assert!(pingCall::abi_decode_flexible(&[], true).is_ok());
+
+    // arguments that start with the selector bytes are ambiguous
+    let call = registerCall { selector: registerCall::SELECTOR.into(), name: "a".into() };
+    let raw = &call.abi_encode()[4..];
+    assert!(registerCall::abi_decode_raw(raw, true).is_ok());
+    assert!(registerCall::abi_decode_flexible(raw, true).is_err());
+    let decoded = registerCall::abi_decode_flexible(&call.abi_encode(), true).unwrap();
+    assert_eq!(decoded.selector, call.selector);
+    assert_eq!(decoded.name, call.name);
+}
+
+#[test]
+fn contract_decode_call_event() {
+    sol! {
+        contract Token {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+            event Approval(address indexed owner, address indexed spender, uint256 value);
+
+            function transfer(address to, uint256 amount) returns (bool);
+            function approve(address spender, uint256 amount) returns (bool);
+        }
+    }
+
+    let to = address!("bBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB");
+    let data = hex!(
+        "a9059cbb"
+        "000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+        "0000000000000000000000000000000000000000000000000000000000000064"
+    );
+    match Token::decode_call(&data, true).unwrap() {
+        Token::TokenCalls::transfer(call) => {
+            assert_eq!(call.to, to);
+            assert_eq!(call.amount, U256::from(100));
+        }
+        call => panic!("unexpected call: {call:?}"),
+    }
+    assert!(Token::decode_call(&data[4..], true).is_err());
+
+    let from = Address::repeat_byte(0x11);
+    let log = alloy_primitives::Log::new_unchecked(
+        Address::repeat_byte(0x22),
+        vec![Token::Transfer::SIGNATURE_HASH, from.into_word(), to.into_word()],
+        U256::from(100).to_be_bytes_vec().into(),
+    );
+    match Token::decode_event(&log, true).unwrap() {
+        Token::TokenEvents::Transfer(event) => {
+            assert_eq!(event.from, from);
+            assert_eq!(event.to, to);
+            assert_eq!(event.value, U256::from(100));
+        }
+        event => panic!("unexpected event: {event:?}"),
+    }
+
+    let mut log = log;
+    log.data.topics_mut()[0] = keccak256("Unknown()");
+    assert!(Token::decode_event(&log, true).is_err());
+}
+
+#[test]
+fn named_returns() {
+    sol! {
+        function withdraw(uint256 shares) returns (uint256 amount, bool success);
+        function partial() returns (uint256 amount, bool);
+        function none();
+    }
+
+    assert_eq!(withdrawCall::RETURN_NAMES, ["amount", "success"]);
+    assert_eq!(partialCall::RETURN_NAMES, ["amount", "_1"]);
+    assert!(noneCall::RETURN_NAMES.is_empty());
+
+    let ret = withdrawReturn { amount: U256::from(10), success: true };
+    let data = ret.abi_encode();
+    let decoded = withdrawCall::abi_decode_returns(&data, true).unwrap();
+    assert_eq!(decoded.amount, U256::from(10));
+    assert!(decoded.success);
+
+    let decoded = partialCall::abi_decode_returns(&data, true).unwrap();
+    assert_eq!(decoded.amount, U256::from(10));
+    assert!(decoded._1);
+}
+
+// Function bodies, including inline assembly, are parsed but otherwise ignored
+#[test]
+fn function_body_assembly() {
+    sol! {
+        contract Hasher {
+            function hash(address a, uint256 x) external pure returns (bytes32 r) {
+                assembly ("memory-safe") {
+                    let ptr := mload(0x40)
+                    mstore(ptr, shl(96, a))
+                    switch x
+                    case 0 { r := 0 }
+                    default { r := keccak256(ptr, 0x14) }
+                    for { let i := 0 } lt(i, x) { i := add(i, 1) } {
+                        if iszero(i) { continue }
+                        mstore(add(ptr, 0x20), i)
+                    }
+                    function double(y) -> z { z := add(y, y) leave }
+                    let b, c := double(1)
+                }
+                return r;
+            }
+        }
+    }
+    use Hasher::*;
+
+    assert_eq!(hashCall::SIGNATURE, "hash(address,uint256)");
+    let _ = HasherCalls::hash(hashCall { a: Address::ZERO, x: U256::ZERO });
+    let _ = hashReturn { r: Default::default() };
+}
+
+// Modern statement forms in function bodies are parsed but otherwise ignored
+#[test]
+fn function_body_statements() {
+    sol! {
+        interface IOracle {
+            function price(address token) external view returns (uint256);
+        }
+
+        contract Aggregator {
+            error Failed(bytes reason);
+            event Quoted(address indexed token, uint256 price);
+
+            function sum(uint256[] calldata xs) external pure returns (uint256 total) {
+                unchecked {
+                    for (uint256 i; i < xs.length; ++i) total += xs[i];
+                }
+            }
+
+            function quote(address oracle, address token) external returns (uint256) {
+                try IOracle(oracle).price(token) returns (uint256 p) {
+                    if (p == 0) revert Failed("");
+                    else emit Quoted(token, p);
+                    return p;
+                } catch Error(string memory reason) {
+                    revert Failed(bytes(reason));
+                } catch Panic(uint256 code) {
+                    revert Failed(abi.encode(code));
+                } catch (bytes memory reason) {
+                    revert Failed(reason);
+                }
+            }
+
+            function loop(uint256 n) external pure returns (uint256 x) {
+                do {
+                    x = n > 10 ? x + 2 : x + 1;
+                    (uint256 a, ) = (x, n);
+                    delete a;
+                } while (x < n);
+                assembly {
+                    if gt(x, 100) { x := 100 }
+                }
+            }
+        }
+    }
+    use Aggregator::*;
+
+    assert_eq!(sumCall::SIGNATURE, "sum(uint256[])");
+    assert_eq!(quoteCall::SIGNATURE, "quote(address,address)");
+    assert_eq!(loopCall::SIGNATURE, "loop(uint256)");
+    assert_eq!(Failed::SIGNATURE, "Failed(bytes)");
+    assert_eq!(Quoted::SIGNATURE, "Quoted(address,uint256)");
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn call_provider() {
+    use alloy_primitives::{address, Bytes};
+    use alloy_sol_types::{CallProvider, ContractError, Error, Revert, SolValue};
+    use std::{
+        future::{ready, Future, Ready},
+        sync::Arc,
+        task::{Context, Poll, Wake},
+    };
+
+    sol! {
+        contract Token {
+            error Blocked(address account);
+
+            function balanceOf(address owner) returns (uint256 balance);
+        }
+
+        function ping() returns (bool);
+    }
+    use Token::{balanceOfCall, Blocked, TokenErrors};
+
+    struct MockProvider;
+
+    impl CallProvider for MockProvider {
+        type CallFuture<'a> = Ready<alloy_sol_types::Result<Bytes>>;
+
+        fn eth_call(&self, to: Address, data: Bytes) -> Self::CallFuture<'_> {
+            if data[..] == (pingCall {}).abi_encode() {
+                return ready(Err(Error::Reverted(hex!("deadbeef").into())));
+            }
+            let call = balanceOfCall::abi_decode(&data, true).unwrap();
+            let revert = match call.owner.0[19] {
+                0 => Revert::from("zero address").abi_encode(),
+                1 => Blocked { account: call.owner }.abi_encode(),
+                _ => return ready(Ok(U256::from_be_bytes(to.into_word().0).abi_encode().into())),
+            };
+            ready(Err(Error::Reverted(revert.into())))
+        }
+    }
+
+    fn block_on<F: Future>(f: F) -> F::Output {
+        struct NoopWaker;
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+        let waker = Arc::new(NoopWaker).into();
+        let mut cx = Context::from_waker(&waker);
+        let mut f = Box::pin(f);
+        loop {
+            if let Poll::Ready(output) = f.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    let to = address!("00000000000000000000000000000000000000ff");
+    let call = balanceOfCall { owner: address!("0000000000000000000000000000000000000002") };
+    let ret = block_on(call.call(&MockProvider, to)).unwrap().unwrap();
+    assert_eq!(ret.balance, U256::from(0xff));
+
+    let call = balanceOfCall { owner: Address::ZERO };
+    match block_on(call.call(&MockProvider, to)).unwrap() {
+        Err(ContractError::Revert(revert)) => assert_eq!(revert.reason(), "zero address"),
+        ret => panic!("unexpected result: {ret:?}"),
+    }
+
+    let owner = address!("0000000000000000000000000000000000000001");
+    let call = balanceOfCall { owner };
+    match block_on(call.call(&MockProvider, to)).unwrap() {
+        Err(ContractError::CustomError(TokenErrors::Blocked(e))) => assert_eq!(e.account, owner),
+        ret => panic!("unexpected result: {ret:?}"),
+    }
+
+    // revert data that is not a known error is returned as is
+    let err = block_on((pingCall {}).call(&MockProvider, to)).unwrap_err();
+    assert!(matches!(&err, Error::Reverted(data) if data[..] == hex!("deadbeef")), "{err:?}");
+}
@@ -0,0 +1,63 @@
+use alloy_primitives::{address, hex, U256};
+use alloy_sol_types::{
+    private::alloy_rlp::{self, Decodable, Encodable},
+    sol,
+};
+
+sol! {
+    #[sol(rlp)]
+    #[derive(Debug, PartialEq)]
+    struct Inner {
+        bool flag;
+        uint64 nonce;
+    }
+
+    #[sol(rlp)]
+    #[derive(Debug, PartialEq)]
+    struct Outer {
+        uint256 value;
+        address to;
+        bytes data;
+        string memo;
+        Inner inner;
+    }
+}
+
+#[test]
+fn rlp_roundtrip() {
+    let outer = Outer {
+        value: U256::from(256),
+        to: address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"),
+        data: hex!("deadbeef").to_vec(),
+        memo: "hi".into(),
+        inner: Inner { flag: true, nonce: 0 },
+    };
+
+    let encoded = alloy_rlp::encode(&outer);
+    assert_eq!(encoded.len(), outer.length());
+    assert_eq!(
+        hex::encode(&encoded),
+        concat!(
+            "e3",
+            "820100",
+            "94d8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "84deadbeef",
+            "826869",
+            "c20180",
+        )
+    );
+    assert_eq!(Outer::decode(&mut &encoded[..]).unwrap(), outer);
+}
+
+#[test]
+fn rlp_decode_errors() {
+    // not a list
+    assert_eq!(Inner::decode(&mut &hex!("820180")[..]), Err(alloy_rlp::Error::UnexpectedString));
+    // list with an extra item
+    assert_eq!(
+        Inner::decode(&mut &hex!("c3018001")[..]),
+        Err(alloy_rlp::Error::ListLengthMismatch { expected: 3, got: 2 })
+    );
+    // truncated payload
+    assert_eq!(Inner::decode(&mut &hex!("c301")[..]), Err(alloy_rlp::Error::InputTooShort));
+}
@@ -0,0 +1,27 @@
+use alloy_sol_types::sol;
+
+sol! {
+    #[sol(copy)]
+    struct NotCopy {
+        address a;
+        uint256[] b;
+    }
+}
+
+sol! {
+    struct Inner {
+        uint256 a;
+    }
+
+    #[sol(copy)]
+    struct NestedNotCopy {
+        Inner inner;
+    }
+}
+
+sol! {
+    #[sol(copy)]
+    function notCopy(address a) returns (string);
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use alloy_sol_types::sol;
+
+sol! {
+    function notBytes(#[sol(max_bytes = 4)] uint256 a);
+}
+
+sol! {
+    struct NotBytesField {
+        #[sol(max_bytes = 4)]
+        bytes32 a;
+    }
+}
+
+sol! {
+    function returns() returns (#[sol(max_bytes = 4)] bytes a);
+}
+
+sol! {
+    event EventParam(#[sol(max_bytes = 4)] bytes a);
+}
+
+sol! {
+    error ErrorParam(#[sol(max_bytes = 4)] bytes a);
+}
+
+sol! {
+    contract C {
+        constructor(#[sol(max_bytes = 4)] bytes a) {}
+    }
+}
+
+fn main() {}
@@ -17,6 +17,10 @@ pub use path::SolPath;
 /// identifiers for Solidity keywords.
 static KW_DIFFERENCE: &[&str] = &include!("./difference.expr");
 
+/// The intersection of the Rust and Solidity keyword sets. These are not valid Solidity
+/// identifiers, but they can still appear in JSON ABIs, so we emit raw identifiers for them too.
+static KW_INTERSECTION: &[&str] = &include!("./intersection.expr");
+
 /// A Solidity identifier.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -98,7 +102,7 @@ impl SolIdent {
     }
 
     pub fn new_spanned(mut s: &str, span: Span) -> Self {
-        let mut new_raw = KW_DIFFERENCE.contains(&s);
+        let mut new_raw = KW_DIFFERENCE.contains(&s) || KW_INTERSECTION.contains(&s);
 
         if s.starts_with("r#") {
             new_raw = true;
@@ -177,12 +181,13 @@ mod tests {
             assert_eq!(id.as_string(), s);
         }
 
-        // keywords in both languages; we don't make them "raw" because they are always invalid.
+        // keywords in both languages; these are invalid in Solidity source code, but may appear in
+        // JSON ABIs, so we make them "raw" as well.
         let intersection: &[&str] = &include!("./intersection.expr");
         for &s in intersection {
             let id: SolIdent = syn::parse_str(s).unwrap();
             assert_eq!(id, SolIdent::new(s));
-            assert_eq!(id.to_string(), s);
+            assert_eq!(id.to_string(), format!("r#{s}"));
             assert_eq!(id.as_string(), s);
         }
     }